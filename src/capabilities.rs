@@ -0,0 +1,179 @@
+//! Parsing of the DDC/CI capabilities string returned by [`Monitor::win32_capabilities`][caps]
+//!
+//! The capabilities string is a nested parenthesized key/value grammar, e.g.
+//! `(prot(monitor)type(lcd)model(XYZ)cmds(01 02 03)vcp(02 04 14(05 08 0B) 60(01 03 11)))`.
+//! This is specified by the MCCS/DDC-CI standard rather than any particular Windows API, so
+//! the parser here does not depend on any `windows` types.
+//!
+//! [caps]: crate::Monitor::win32_capabilities
+
+use std::collections::BTreeMap;
+
+/// A parsed DDC/CI capabilities string
+///
+/// See the [module documentation](self) for the grammar this decodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The `prot` field, e.g. `monitor`
+    pub protocol: Option<String>,
+    /// The `type` field, e.g. `lcd` or `crt`
+    pub kind: Option<String>,
+    /// The `model` field, the monitor's model name
+    pub model: Option<String>,
+    /// The `cmds` field, the set of supported DDC/CI command codes
+    pub commands: Vec<u8>,
+    /// The `vcp` field, a map from VCP feature code to its permitted values
+    ///
+    /// `None` means the feature is continuous; `Some(values)` lists the discrete values
+    /// permitted for a non-continuous feature.
+    pub vcp: BTreeMap<u8, Option<Vec<u8>>>,
+    /// The `mswhql` field, present when the monitor claims MS-WHQL certification
+    pub mswhql: Option<String>,
+    /// The `mccs_ver` field, e.g. `2.1`
+    pub mccs_ver: Option<String>,
+    /// Any other top-level fields, stored as their raw, unparsed value bytes
+    pub unknown: Vec<(String, Vec<u8>)>,
+}
+
+impl Capabilities {
+    /// Parse a raw capabilities string as returned by
+    /// [`Monitor::win32_capabilities`](crate::Monitor::win32_capabilities)
+    ///
+    /// Returns `None` if `data` is not a single well-formed parenthesized group.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let mut cursor = Cursor::new(data);
+        cursor.skip_ws();
+        let inner = cursor.group()?;
+
+        let mut caps = Capabilities::default();
+        let mut cursor = Cursor::new(inner);
+        loop {
+            cursor.skip_ws();
+            if cursor.is_empty() {
+                break
+            }
+
+            let key = cursor.ident()?;
+            let value = cursor.group()?;
+            match key {
+                "prot" => caps.protocol = Some(ascii_trimmed(value)),
+                "type" => caps.kind = Some(ascii_trimmed(value)),
+                "model" => caps.model = Some(ascii_trimmed(value)),
+                "cmds" => caps.commands = parse_hex_list(value),
+                "vcp" => caps.vcp = parse_vcp(value),
+                "mswhql" => caps.mswhql = Some(ascii_trimmed(value)),
+                "mccs_ver" => caps.mccs_ver = Some(ascii_trimmed(value)),
+                key => caps.unknown.push((key.to_owned(), value.to_owned())),
+            }
+        }
+
+        Some(caps)
+    }
+}
+
+fn parse_vcp(value: &[u8]) -> BTreeMap<u8, Option<Vec<u8>>> {
+    let mut vcp = BTreeMap::new();
+    let mut cursor = Cursor::new(value);
+    loop {
+        cursor.skip_ws();
+        let Some(code) = cursor.hex_byte() else { break };
+
+        let values = match cursor.peek() {
+            Some(b'(') => cursor.group().map(parse_hex_list),
+            _ => None,
+        };
+        vcp.insert(code, values);
+    }
+    vcp
+}
+
+fn parse_hex_list(value: &[u8]) -> Vec<u8> {
+    let mut values = Vec::new();
+    let mut cursor = Cursor::new(value);
+    loop {
+        cursor.skip_ws();
+        let Some(byte) = cursor.hex_byte() else { break };
+        values.push(byte);
+    }
+    values
+}
+
+fn ascii_trimmed(value: &[u8]) -> String {
+    String::from_utf8_lossy(value).trim().to_owned()
+}
+
+/// A minimal byte cursor for the capabilities grammar: nested `(...)` groups and
+/// whitespace-separated hex tokens, with no escaping.
+struct Cursor<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn new(rest: &'a [u8]) -> Self {
+        Cursor { rest }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.rest.first().copied()
+    }
+
+    fn skip_ws(&mut self) {
+        while matches!(self.peek(), Some(b) if b.is_ascii_whitespace()) {
+            self.rest = &self.rest[1..];
+        }
+    }
+
+    fn take_while(&mut self, pred: impl Fn(u8) -> bool) -> &'a [u8] {
+        let end = self.rest.iter().position(|&b| !pred(b)).unwrap_or(self.rest.len());
+        let (taken, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        taken
+    }
+
+    fn ident(&mut self) -> Option<&'a str> {
+        let ident = self.take_while(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if ident.is_empty() {
+            None
+        } else {
+            std::str::from_utf8(ident).ok()
+        }
+    }
+
+    fn hex_byte(&mut self) -> Option<u8> {
+        let digits = self.take_while(|b| b.is_ascii_hexdigit());
+        if digits.is_empty() || digits.len() > 2 {
+            return None
+        }
+        u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok()
+    }
+
+    /// Consume a `(...)` group, respecting nested parentheses, and return its inner bytes.
+    fn group(&mut self) -> Option<&'a [u8]> {
+        if self.peek() != Some(b'(') {
+            return None
+        }
+        let mut depth = 0usize;
+        let mut end = None;
+        for (i, &b) in self.rest.iter().enumerate() {
+            match b {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = Some(i);
+                        break
+                    }
+                },
+                _ => (),
+            }
+        }
+        let end = end?;
+        let inner = &self.rest[1..end];
+        self.rest = &self.rest[end + 1..];
+        Some(inner)
+    }
+}
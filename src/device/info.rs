@@ -1,5 +1,5 @@
 use {
-    super::{InfoPropertyValue, InfoSet, Property, PropertyKey, PropertyTypeMod},
+    super::{DevNode, InfoPropertyValue, InfoSet, Property, PropertyKey, PropertyTypeMod},
     crate::{
         registry,
         win32::{win32_error, Guid},
@@ -16,8 +16,8 @@ use {
         Win32::{
             Devices::{
                 DeviceAndDriverInstallation::{
-                    SetupDiGetDevicePropertyKeys, SetupDiGetDevicePropertyW, SetupDiOpenDevRegKey, HDEVINFO,
-                    SP_DEVINFO_DATA,
+                    SetupDiGetDevicePropertyKeys, SetupDiGetDevicePropertyW, SetupDiOpenDevRegKey,
+                    SetupDiSetDevicePropertyW, HDEVINFO, SP_DEVINFO_DATA,
                 },
                 Properties::DEVPROPKEY,
             },
@@ -106,6 +106,69 @@ impl<'s> Info<'s> {
         })
     }
 
+    /// Write a property value to this device
+    ///
+    /// This is a wrapper around [`SetupDiSetDevicePropertyW`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdisetdevicepropertyw
+    #[doc(alias = "SetupDiSetDevicePropertyW")]
+    pub fn set_property(&self, key: &PropertyKey, value: &Property) -> WinResult<()> {
+        self.win32_set_property(key.as_ref(), value.type_.win32_devprop_type(), value.data())
+    }
+
+    /// Read this device's cached EDID out of its hardware registry key
+    ///
+    /// This opens the `Device Parameters` registry key via
+    /// [`open_registry_key`](Self::open_registry_key) and reads the `EDID` binary value.
+    pub fn edid(&self) -> WinResult<Vec<u8>> {
+        let (_, data) = self.open_registry_key()?.win32_query_value(widecstr!("EDID"))?;
+        Ok(data)
+    }
+
+    /// This device's devnode, addressed directly by [`instance`](Self::instance)
+    ///
+    /// This is the entry point for the Config Manager device-tree walks below, since `Info` is
+    /// only ever reachable through a [`SetupDiEnumDeviceInfo`](InfoSet::enumerate) enumeration
+    /// while [`DevNode`] can address any devnode by its raw `DEVINST`.
+    pub fn devnode(&self) -> DevNode {
+        DevNode::from_win32(self.instance())
+    }
+
+    /// The devnode directly above this one in the device tree, e.g. a monitor's display adapter
+    ///
+    /// This is a thin wrapper around [`DevNode::parent`], letting callers walk from a
+    /// [`DEVCLASS_MONITOR`](super::DEVCLASS_MONITOR) node up to its
+    /// [`DEVCLASS_DISPLAY`](super::DEVCLASS_DISPLAY) GPU node directly, rather than correlating
+    /// them by [`DEVICE_PARENT`](PropertyKey::DEVICE_PARENT) as [`matches_device`](Self::matches_device) does.
+    pub fn parent(&self) -> WinResult<DevNode> {
+        self.devnode().parent()
+    }
+
+    /// Walk this devnode's children
+    ///
+    /// This is a thin wrapper around [`DevNode::children`].
+    pub fn children(&self) -> impl Iterator<Item = WinResult<DevNode>> {
+        self.devnode().children()
+    }
+
+    /// Walk the other devnodes sharing this one's parent
+    ///
+    /// This is a thin wrapper around [`DevNode::siblings`].
+    pub fn siblings(&self) -> impl Iterator<Item = WinResult<DevNode>> {
+        self.devnode().siblings()
+    }
+
+    /// Open this device's `Device Parameters` registry key with the requested access rights
+    ///
+    /// Unlike [`open_registry_key`](Self::open_registry_key), which hardcodes [`KEY_READ`], this
+    /// lets callers request write access, e.g. to persist a friendly override name or
+    /// last-known EDID against the devnode.
+    #[doc(alias = "SetupDiOpenDevRegKey")]
+    pub fn open_registry_key_access(&self, access: REG_SAM_FLAGS) -> WinResult<registry::Key> {
+        self.win32_open_registry_key(true, 0, true, access)
+            .map(|reg| unsafe { registry::Key::from_win32(reg) })
+    }
+
     /// Whether this device info matches a [display](DisplayDevice)
     /// or [monitor device](crate::MonitorDevice)
     pub fn matches_device(&self, device: &DisplayDevice) -> WinResult<bool> {
@@ -160,8 +223,7 @@ impl<'s> Info<'s> {
 
     #[doc(alias = "SetupDiOpenDevRegKey")]
     pub fn open_registry_key(&self) -> WinResult<registry::Key> {
-        self.win32_open_registry_key(true, 0, true, KEY_READ)
-            .map(|reg| unsafe { registry::Key::from_win32(reg) })
+        self.open_registry_key_access(KEY_READ)
     }
 
     pub fn from_win32(info: SP_DEVINFO_DATA) -> Self {
@@ -206,6 +268,12 @@ impl<'s> Info<'s> {
         PropertyTypeMod::try_from_win32(prop_type).map(|type_| Property::new(type_, data))
     }
 
+    #[doc(alias = "SetupDiSetDevicePropertyW")]
+    pub fn win32_set_property(&self, key: &DEVPROPKEY, prop_type: u32, data: &[u8]) -> WinResult<()> {
+        let handle = self.win32_handle()?;
+        unsafe { SetupDiSetDevicePropertyW(handle, &self.info, key, prop_type, Some(data), 0) }
+    }
+
     #[doc(alias = "SetupDiGetDevicePropertyKeys")]
     pub fn win32_property_keys(&self) -> WinResult<Vec<DEVPROPKEY>> {
         let handle = self.win32_handle()?;
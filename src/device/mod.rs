@@ -11,13 +11,25 @@
 
 pub use self::{
     info::Info,
-    prop::{InfoPropertyValue, Property, PropertyKey, PropertyType, PropertyTypeMod},
+    node::DevNode,
+    notify::{wait_no_pending_install_events, DeviceInstanceEvent, DeviceInstanceWatcher, MonitorEvent, MonitorWatcher},
+    prop::{
+        Currency, Decimal, InfoPropertyValue, Property, PropertyKey, PropertyType, PropertyTypeMod, PropertyValue,
+        PropertyValueMod,
+    },
+    query::{
+        DevObject, DevObjectQuery, DevQueryEvent, DevQueryState, DeviceQuery, FilterExpr, FilterOrdering,
+        FilterSubstring, PropertyFilter,
+    },
     set::InfoSet,
 };
 use {crate::win32::Guid, windows::Win32::Devices::DeviceAndDriverInstallation};
 
 mod info;
+mod node;
+mod notify;
 mod prop;
+mod query;
 mod set;
 
 /// A [display device class](DeviceAndDriverInstallation::GUID_DEVCLASS_DISPLAY)
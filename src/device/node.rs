@@ -0,0 +1,372 @@
+//! [Config Manager][cfgmgr32] device node ("devnode") access
+//!
+//! Unlike [`Info`](super::Info), a [`DevNode`] needs no [`InfoSet`](super::InfoSet) handle: it
+//! is addressed directly by its opaque [`DEVINST`][devinst] handle, resolved from a device
+//! interface path. This is the layer [`MonitorDevice::device_node`](crate::MonitorDevice::device_node)
+//! and [`Monitor::device_node`](crate::Monitor::device_node) use to read live properties off a
+//! monitor's PnP devnode.
+//!
+//! [cfgmgr32]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-functions
+//! [devinst]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/devinst
+
+use {
+    super::{InfoPropertyValue, Property, PropertyKey, PropertyTypeMod},
+    crate::{registry, win32::win32_error},
+    std::fmt::{self, Debug, Formatter},
+    widestring::{widecstr, WideCStr, WideCString, WideStr},
+    windows::{
+        core::{Result as WinResult, PCWSTR, PWSTR},
+        Win32::{
+            Devices::{
+                Display::GUID_DEVINTERFACE_MONITOR,
+                DeviceAndDriverInstallation::{
+                    CM_Get_Child, CM_Get_DevNode_PropertyW, CM_Get_DevNode_Property_KeysW, CM_Get_Device_IDW,
+                    CM_Get_Device_ID_Size, CM_Get_Device_Interface_ListW, CM_Get_Device_Interface_ListSize,
+                    CM_Get_Device_Interface_PropertyW, CM_Get_Parent, CM_Get_Sibling, CM_Locate_DevNodeW,
+                    CM_MapCrToWin32Err, CM_Open_DevNode_Key, CM_LOCATE_DEVNODE_NORMAL, CM_REGISTRY_HARDWARE,
+                    CONFIGRET, CR_BUFFER_SMALL, CR_NO_SUCH_DEVNODE, CR_SUCCESS, RegDisposition_OpenExisting,
+                },
+                Properties::DEVPROPKEY,
+            },
+            Foundation::{ERROR_INVALID_DATA, ERROR_NOT_FOUND, WIN32_ERROR},
+            System::Registry::KEY_READ,
+        },
+    },
+};
+
+/// A [Config Manager devnode][devinst], addressed by its raw `DEVINST` handle
+///
+/// [devinst]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/devinst
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[doc(alias = "DEVINST")]
+pub struct DevNode {
+    instance: u32,
+}
+
+impl DevNode {
+    /// Resolve the devnode for a device's interface path
+    ///
+    /// This reads [`PropertyKey::DEVICE_INSTANCE_ID`] off the device interface via
+    /// [`CM_Get_Device_Interface_PropertyW`][wraps], then resolves that instance ID to a
+    /// `DEVINST` via [`CM_Locate_DevNodeW`].
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_device_interface_propertyw
+    pub fn from_interface_path(interface_path: &WideStr) -> WinResult<Self> {
+        let interface_path = WideCString::from_ustr_truncate(interface_path);
+        let instance_id =
+            Self::win32_interface_property(&interface_path, PropertyKey::DEVICE_INSTANCE_ID.as_ref())?;
+        let instance_id = instance_id
+            .get::<WideCString>()
+            .ok_or_else(|| win32_error(ERROR_INVALID_DATA, &format_args!("devnode instance id was not a string")))?;
+        Self::from_instance_id(&instance_id)
+    }
+
+    /// Resolve a devnode by its instance ID, via [`CM_Locate_DevNodeW`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_locate_devnodew
+    #[doc(alias = "CM_Locate_DevNodeW")]
+    pub fn from_instance_id(instance_id: &WideCStr) -> WinResult<Self> {
+        let mut instance = 0u32;
+        cr_result(unsafe {
+            CM_Locate_DevNodeW(&mut instance, PCWSTR(instance_id.as_ptr()), CM_LOCATE_DEVNODE_NORMAL)
+        })?;
+        Ok(Self { instance })
+    }
+
+    /// The raw `DEVINST` handle
+    pub const fn instance(&self) -> u32 {
+        self.instance
+    }
+
+    /// Retrieve a particular device property, if it exists
+    ///
+    /// This is a wrapper around [`CM_Get_DevNode_PropertyW`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_devnode_propertyw
+    #[doc(alias = "CM_Get_DevNode_PropertyW")]
+    pub fn property(&self, key: &PropertyKey) -> WinResult<Option<Property>> {
+        match self.win32_property(key.as_ref()) {
+            Err(e) if e.code() == ERROR_NOT_FOUND.to_hresult() => Ok(None),
+            res => res.map(Some),
+        }
+    }
+
+    /// [Retrieve a device property](Self::property), then [convert it to `T`](InfoPropertyValue)
+    #[doc(alias = "CM_Get_DevNode_PropertyW")]
+    pub fn get<T: for<'v> InfoPropertyValue<'v>>(&self, key: &PropertyKey) -> WinResult<Option<T>> {
+        self.property(key).and_then(|v| match v {
+            Some(v) => match v.get() {
+                Some(v) => Ok(Some(v)),
+                None => Err(win32_error(
+                    ERROR_INVALID_DATA,
+                    &format_args!("property {:?} data did not conform to requested type {}", key, T::TYPE),
+                )),
+            },
+            None => Ok(None),
+        })
+    }
+
+    /// Enumerate the names of all properties present on this devnode
+    ///
+    /// This is a wrapper around [`CM_Get_DevNode_Property_KeysW`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_devnode_property_keysw
+    #[doc(alias = "CM_Get_DevNode_Property_KeysW")]
+    pub fn property_keys(&self) -> WinResult<impl Iterator<Item = PropertyKey>> {
+        self.win32_property_keys()
+            .map(|keys| keys.into_iter().map(PropertyKey::from_win32))
+    }
+
+    /// Enumerate all properties and their data present on this devnode
+    #[doc(alias = "CM_Get_DevNode_Property_KeysW")]
+    pub fn all_properties(&self) -> WinResult<Vec<(PropertyKey, Property)>> {
+        self.property_keys()?
+            .map(|key| self.win32_property(key.as_ref()).map(|v| (key, v)))
+            .collect()
+    }
+
+    /// Read this devnode's cached EDID out of its hardware registry key
+    ///
+    /// This opens the devnode's hardware key via [`win32_open_hardware_key`](Self::win32_open_hardware_key),
+    /// descends into its `Device Parameters` subkey, and reads the `EDID` binary value.
+    pub fn read_edid(&self) -> WinResult<Vec<u8>> {
+        let key = self.win32_open_hardware_key()?;
+        let params = key.win32_open(widecstr!("Device Parameters"), Default::default(), KEY_READ)?;
+        let (_, data) = params.win32_query_value(widecstr!("EDID"))?;
+        Ok(data)
+    }
+
+    /// Enumerate all present [`GUID_DEVINTERFACE_MONITOR`] device interface paths
+    ///
+    /// This is a wrapper around [`CM_Get_Device_Interface_ListW`][wraps], and is used as a
+    /// fallback to locate a monitor's devnode when direct correlation fails.
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_device_interface_listw
+    #[doc(alias = "CM_Get_Device_Interface_ListW")]
+    pub fn win32_monitor_interfaces() -> WinResult<Vec<WideCString>> {
+        let guid = GUID_DEVINTERFACE_MONITOR;
+        let mut len = 0u32;
+        cr_result(unsafe { CM_Get_Device_Interface_ListSize(&mut len, &guid, PCWSTR::null(), 0) })?;
+        let mut buf = vec![0u16; len as usize];
+        cr_result(unsafe { CM_Get_Device_Interface_ListW(&guid, PCWSTR::null(), &mut buf, 0) })?;
+        Ok(buf
+            .split(|&c| c == 0)
+            .filter(|s| !s.is_empty())
+            .map(|s| WideCString::from_vec_truncate(s.to_vec()))
+            .collect())
+    }
+
+    /// Resolve the devnode of every present monitor device interface
+    ///
+    /// This is a fallback for when a monitor cannot be directly correlated to an interface path,
+    /// e.g. via [`from_interface_path`](Self::from_interface_path).
+    pub fn find_monitor() -> WinResult<impl Iterator<Item = WinResult<Self>>> {
+        Ok(Self::win32_monitor_interfaces()?
+            .into_iter()
+            .map(|path| Self::from_interface_path(path.as_ustr())))
+    }
+
+    /// Open this devnode's hardware registry key
+    ///
+    /// This is a wrapper around [`CM_Open_DevNode_Key`][wraps], opening the same key that backs
+    /// `HKEY_LOCAL_MACHINE\SYSTEM\CurrentControlSet\Enum\...\<instance>` for this devnode.
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_open_devnode_key
+    #[doc(alias = "CM_Open_DevNode_Key")]
+    pub fn win32_open_hardware_key(&self) -> WinResult<registry::Key> {
+        let mut handle = Default::default();
+        cr_result(unsafe {
+            CM_Open_DevNode_Key(
+                self.instance,
+                KEY_READ.0,
+                0,
+                RegDisposition_OpenExisting,
+                &mut handle,
+                CM_REGISTRY_HARDWARE,
+            )
+        })?;
+        Ok(unsafe { registry::Key::from_win32(handle) })
+    }
+
+    /// This devnode's instance ID, e.g. `DISPLAY\ACI27EE\4&1234abcd&0&UID0`
+    ///
+    /// This is a wrapper around [`CM_Get_Device_IDW`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_device_idw
+    #[doc(alias = "CM_Get_Device_IDW")]
+    pub fn device_id(&self) -> WinResult<WideCString> {
+        let mut len = 0u32;
+        cr_result(unsafe { CM_Get_Device_ID_Size(&mut len, self.instance, 0) })?;
+        let mut buf = vec![0u16; len as usize + 1];
+        cr_result(unsafe { CM_Get_Device_IDW(self.instance, PWSTR(buf.as_mut_ptr()), len + 1, 0) })?;
+        Ok(WideCString::from_vec_truncate(buf))
+    }
+
+    /// The devnode directly above this one in the device tree, e.g. a monitor's display adapter
+    ///
+    /// This is a wrapper around [`CM_Get_Parent`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_parent
+    #[doc(alias = "CM_Get_Parent")]
+    pub fn parent(&self) -> WinResult<Self> {
+        let mut instance = 0u32;
+        cr_result(unsafe { CM_Get_Parent(&mut instance, self.instance, 0) })?;
+        Ok(Self { instance })
+    }
+
+    /// The first child of this devnode, if any
+    ///
+    /// This is a wrapper around [`CM_Get_Child`][wraps]. Use [`children`](Self::children) to walk
+    /// every child via the sibling chain.
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_child
+    #[doc(alias = "CM_Get_Child")]
+    pub fn child(&self) -> WinResult<Self> {
+        Self::step_child(self.instance).unwrap_or_else(|| Err(cr_error(CR_NO_SUCH_DEVNODE)))
+    }
+
+    /// The next devnode sharing this one's parent, if any
+    ///
+    /// This is a wrapper around [`CM_Get_Sibling`][wraps]. Use [`siblings`](Self::siblings) to
+    /// walk the remainder of the chain.
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_get_sibling
+    #[doc(alias = "CM_Get_Sibling")]
+    pub fn sibling(&self) -> WinResult<Self> {
+        Self::step_sibling(self.instance).unwrap_or_else(|| Err(cr_error(CR_NO_SUCH_DEVNODE)))
+    }
+
+    /// Walk all children of this devnode, starting at [`child`](Self::child) and following the
+    /// sibling chain
+    ///
+    /// Unlike [`child`](Self::child), this stops cleanly once the chain is exhausted instead of
+    /// yielding a final `CR_NO_SUCH_DEVNODE` error.
+    pub fn children(&self) -> impl Iterator<Item = WinResult<Self>> {
+        std::iter::successors(Self::step_child(self.instance), |prev| match prev {
+            Ok(node) => Self::step_sibling(node.instance),
+            Err(_) => None,
+        })
+    }
+
+    /// Walk the remaining devnodes sharing this one's parent, starting at
+    /// [`sibling`](Self::sibling) and following the sibling chain
+    ///
+    /// Unlike [`sibling`](Self::sibling), this stops cleanly once the chain is exhausted instead
+    /// of yielding a final `CR_NO_SUCH_DEVNODE` error.
+    pub fn siblings(&self) -> impl Iterator<Item = WinResult<Self>> {
+        std::iter::successors(Self::step_sibling(self.instance), |prev| match prev {
+            Ok(node) => Self::step_sibling(node.instance),
+            Err(_) => None,
+        })
+    }
+
+    fn step_child(instance: u32) -> Option<WinResult<Self>> {
+        let mut next = 0u32;
+        match unsafe { CM_Get_Child(&mut next, instance, 0) } {
+            CR_NO_SUCH_DEVNODE => None,
+            CR_SUCCESS => Some(Ok(Self { instance: next })),
+            cr => Some(Err(cr_error(cr))),
+        }
+    }
+
+    fn step_sibling(instance: u32) -> Option<WinResult<Self>> {
+        let mut next = 0u32;
+        match unsafe { CM_Get_Sibling(&mut next, instance, 0) } {
+            CR_NO_SUCH_DEVNODE => None,
+            CR_SUCCESS => Some(Ok(Self { instance: next })),
+            cr => Some(Err(cr_error(cr))),
+        }
+    }
+}
+
+#[allow(missing_docs)]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "win32")))]
+#[cfg_attr(not(feature = "win32"), doc(hidden))]
+impl DevNode {
+    pub const fn from_win32(instance: u32) -> Self {
+        Self { instance }
+    }
+
+    pub const fn into_win32(self) -> u32 {
+        self.instance
+    }
+
+    #[doc(alias = "CM_Get_DevNode_PropertyW")]
+    pub fn win32_property(&self, key: &DEVPROPKEY) -> WinResult<Property> {
+        let mut prop_type = 0u32;
+        let mut len = 0u32;
+        match unsafe { CM_Get_DevNode_PropertyW(self.instance, key, &mut prop_type, None, &mut len, 0) } {
+            CR_SUCCESS | CR_BUFFER_SMALL => {},
+            cr => return Err(cr_error(cr)),
+        }
+        let mut data = vec![0u8; len as usize];
+        cr_result(unsafe {
+            CM_Get_DevNode_PropertyW(self.instance, key, &mut prop_type, Some(data.as_mut_slice()), &mut len, 0)
+        })?;
+        PropertyTypeMod::try_from_win32(prop_type).map(|type_| Property::new(type_, data))
+    }
+
+    #[doc(alias = "CM_Get_DevNode_Property_KeysW")]
+    pub fn win32_property_keys(&self) -> WinResult<Vec<DEVPROPKEY>> {
+        let mut prop_count = 0u32;
+        match unsafe { CM_Get_DevNode_Property_KeysW(self.instance, None, &mut prop_count, 0) } {
+            CR_SUCCESS | CR_BUFFER_SMALL => {},
+            cr => return Err(cr_error(cr)),
+        }
+        let mut properties = vec![DEVPROPKEY::default(); prop_count as usize];
+        cr_result(unsafe {
+            CM_Get_DevNode_Property_KeysW(self.instance, Some(properties.as_mut_slice()), &mut prop_count, 0)
+        })?;
+        properties.truncate(prop_count as usize);
+        Ok(properties)
+    }
+
+    #[doc(alias = "CM_Get_Device_Interface_PropertyW")]
+    pub fn win32_interface_property(interface_path: &WideCStr, key: &DEVPROPKEY) -> WinResult<Property> {
+        let path = PCWSTR(interface_path.as_ptr());
+        let mut prop_type = 0u32;
+        let mut len = 0u32;
+        match unsafe { CM_Get_Device_Interface_PropertyW(path, key, &mut prop_type, None, &mut len, 0) } {
+            CR_SUCCESS | CR_BUFFER_SMALL => {},
+            cr => return Err(cr_error(cr)),
+        }
+        let mut data = vec![0u8; len as usize];
+        cr_result(unsafe {
+            CM_Get_Device_Interface_PropertyW(path, key, &mut prop_type, Some(data.as_mut_slice()), &mut len, 0)
+        })?;
+        PropertyTypeMod::try_from_win32(prop_type).map(|type_| Property::new(type_, data))
+    }
+}
+
+pub(crate) fn cr_error(cr: CONFIGRET) -> windows::core::Error {
+    win32_error(
+        WIN32_ERROR(unsafe { CM_MapCrToWin32Err(cr, ERROR_NOT_FOUND.0) }),
+        &format_args!("CONFIGRET {cr:#x}"),
+    )
+}
+
+pub(crate) fn cr_result(cr: CONFIGRET) -> WinResult<()> {
+    match cr {
+        CR_SUCCESS => Ok(()),
+        cr => Err(cr_error(cr)),
+    }
+}
+
+impl Debug for DevNode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut debug = f.debug_struct("DevNode");
+        debug.field("instance", &self.instance);
+
+        if let Ok(Some(v)) = self.get::<WideCString>(PropertyKey::DEVICE_INSTANCE_ID) {
+            debug.field("instance_id", &v.to_ustring());
+        }
+
+        debug.finish()
+    }
+}
+
+impl From<DevNode> for u32 {
+    fn from(node: DevNode) -> Self {
+        node.into_win32()
+    }
+}
@@ -0,0 +1,280 @@
+//! Monitor hotplug notifications via [Config Manager device notifications][cm-notify]
+//!
+//! [cm-notify]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-register-notification
+
+use {
+    super::node::cr_result,
+    std::{
+        ffi::c_void,
+        fmt::{self, Debug, Formatter},
+        mem, slice,
+    },
+    widestring::{WideCStr, WideString},
+    windows::{
+        core::GUID,
+        Win32::{
+            Devices::{
+                Display::GUID_DEVINTERFACE_MONITOR,
+                DeviceAndDriverInstallation::{
+                    CMP_WaitNoPendingInstallEvents, CM_Register_Notification, CM_Unregister_Notification,
+                    CM_NOTIFY_ACTION, CM_NOTIFY_ACTION_DEVICEINSTANCEENUMERATED, CM_NOTIFY_ACTION_DEVICEINSTANCEREMOVED,
+                    CM_NOTIFY_ACTION_DEVICEINSTANCESTARTED, CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL,
+                    CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL, CM_NOTIFY_EVENT_DATA, CM_NOTIFY_FILTER,
+                    CM_NOTIFY_FILTER_TYPE_DEVICEINSTANCE, CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE, HCMNOTIFICATION,
+                },
+            },
+        },
+    },
+};
+
+/// The byte size of a [`CM_NOTIFY_EVENT_DATA`] header preceding its `DeviceInterface.SymbolicLink`
+/// flexible array member: `FilterType` (4 bytes) + `Reserved` (4 bytes) + `ClassGuid` (16 bytes)
+const DEVICE_INTERFACE_HEADER_SIZE: usize = 4 + 4 + mem::size_of::<GUID>();
+
+/// A monitor hotplug event delivered to a [`MonitorWatcher`] callback
+///
+/// Wraps the [`CM_NOTIFY_ACTION`][wraps] and device interface path of a
+/// [`CM_NOTIFY_EVENT_DATA`][wraps].
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/ns-cfgmgr32-cm_notify_event_data
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum MonitorEvent {
+    /// A monitor device interface was attached, see
+    /// [`CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-notify-action-deviceinterfacearrival
+    Arrival(WideString),
+    /// A monitor device interface was detached, see
+    /// [`CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-notify-action-deviceinterfaceremoval
+    Removal(WideString),
+}
+
+impl MonitorEvent {
+    /// This event's device interface path
+    pub fn interface_path(&self) -> &WideString {
+        match self {
+            Self::Arrival(path) | Self::Removal(path) => path,
+        }
+    }
+
+    unsafe fn from_win32(action: CM_NOTIFY_ACTION, data: *const CM_NOTIFY_EVENT_DATA, data_size: u32) -> Option<Self> {
+        if data.is_null() {
+            return None
+        }
+        let data = &*data;
+        let path_len = (data_size as usize).saturating_sub(DEVICE_INTERFACE_HEADER_SIZE) / mem::size_of::<u16>();
+        let symbolic_link = slice::from_raw_parts(data.u.DeviceInterface.SymbolicLink.as_ptr(), path_len);
+        let path = WideCStr::from_slice_truncate(symbolic_link)
+            .map(|s| s.to_ustring())
+            .unwrap_or_else(|_| WideString::from_vec(symbolic_link.to_vec()));
+
+        match action {
+            CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL => Some(Self::Arrival(path)),
+            CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL => Some(Self::Removal(path)),
+            _ => None,
+        }
+    }
+}
+
+/// A guard registered via [`CM_Register_Notification`][wraps], delivering [`MonitorEvent`]s for
+/// [`GUID_DEVINTERFACE_MONITOR`] device interface arrival/removal to a user-supplied closure
+///
+/// The registration is unregistered via [`CM_Unregister_Notification`] when this is dropped.
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_register_notification
+#[doc(alias = "CM_Register_Notification")]
+pub struct MonitorWatcher {
+    handle: HCMNOTIFICATION,
+    callback: *mut Box<dyn FnMut(MonitorEvent) + Send>,
+}
+
+impl MonitorWatcher {
+    /// Register a new watcher, delivering events to `callback` until this is dropped
+    pub fn new<F: FnMut(MonitorEvent) + Send + 'static>(callback: F) -> windows::core::Result<Self> {
+        let callback: Box<dyn FnMut(MonitorEvent) + Send> = Box::new(callback);
+        let callback = Box::into_raw(Box::new(callback));
+
+        let mut filter = CM_NOTIFY_FILTER::default();
+        filter.cbSize = mem::size_of::<CM_NOTIFY_FILTER>() as u32;
+        filter.FilterType = CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE;
+        unsafe { filter.u.DeviceInterface.ClassGuid = GUID_DEVINTERFACE_MONITOR };
+
+        let mut handle = HCMNOTIFICATION::default();
+        let result = cr_result(unsafe {
+            CM_Register_Notification(&filter, callback as *const c_void, Self::win32_callback, &mut handle)
+        });
+        match result {
+            Ok(()) => Ok(Self { handle, callback }),
+            Err(e) => {
+                drop(unsafe { Box::from_raw(callback) });
+                Err(e)
+            },
+        }
+    }
+
+    unsafe extern "system" fn win32_callback(
+        _handle: HCMNOTIFICATION,
+        context: *const c_void,
+        action: CM_NOTIFY_ACTION,
+        event_data: *const CM_NOTIFY_EVENT_DATA,
+        event_data_size: u32,
+    ) -> u32 {
+        if let Some(event) = MonitorEvent::from_win32(action, event_data, event_data_size) {
+            let callback = &mut *(context as *mut Box<dyn FnMut(MonitorEvent) + Send>);
+            callback(event);
+        }
+        0 // ERROR_SUCCESS
+    }
+}
+
+impl Drop for MonitorWatcher {
+    #[doc(alias = "CM_Unregister_Notification")]
+    fn drop(&mut self) {
+        let _ = unsafe { CM_Unregister_Notification(self.handle) };
+        drop(unsafe { Box::from_raw(self.callback) });
+    }
+}
+
+impl Debug for MonitorWatcher {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MonitorWatcher").field("handle", &self.handle).finish()
+    }
+}
+
+unsafe impl Send for MonitorWatcher {}
+
+/// A hotplug event delivered to a [`DeviceInstanceWatcher`] callback, for the single devnode it
+/// was registered against
+///
+/// Mirrors a [`CM_NOTIFY_ACTION`][wraps] reported against a `CM_NOTIFY_FILTER_TYPE_DEVICEINSTANCE`
+/// registration.
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/ne-cfgmgr32-cm_notify_action
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DeviceInstanceEvent {
+    /// The devnode was (re-)enumerated, see [`CM_NOTIFY_ACTION_DEVICEINSTANCEENUMERATED`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-notify-action-deviceinstanceenumerated
+    Enumerated,
+    /// The devnode's driver started, see [`CM_NOTIFY_ACTION_DEVICEINSTANCESTARTED`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-notify-action-deviceinstancestarted
+    Started,
+    /// The devnode was removed, see [`CM_NOTIFY_ACTION_DEVICEINSTANCEREMOVED`][wraps]
+    ///
+    /// [wraps]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cm-notify-action-deviceinstanceremoved
+    Removed,
+}
+
+impl DeviceInstanceEvent {
+    fn from_win32(action: CM_NOTIFY_ACTION) -> Option<Self> {
+        match action {
+            CM_NOTIFY_ACTION_DEVICEINSTANCEENUMERATED => Some(Self::Enumerated),
+            CM_NOTIFY_ACTION_DEVICEINSTANCESTARTED => Some(Self::Started),
+            CM_NOTIFY_ACTION_DEVICEINSTANCEREMOVED => Some(Self::Removed),
+            _ => None,
+        }
+    }
+}
+
+/// A guard registered via [`CM_Register_Notification`][wraps], delivering [`DeviceInstanceEvent`]s
+/// for a single devnode (e.g. one resolved through [`Info::parent`](super::Info::parent) or
+/// [`DevNode::parent`](super::DevNode::parent)) to a user-supplied closure
+///
+/// Unlike [`MonitorWatcher`], which scopes by device interface class and reports the arriving
+/// interface's path, this scopes by a specific device instance ID and reports only the action
+/// that occurred to it; callers re-locate the device afterwards via its already-known instance ID
+/// (e.g. [`DevNode::from_instance_id`](super::DevNode::from_instance_id)).
+///
+/// The registration is unregistered via [`CM_Unregister_Notification`] when this is dropped.
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/cfgmgr32/nf-cfgmgr32-cm_register_notification
+#[doc(alias = "CM_Register_Notification")]
+pub struct DeviceInstanceWatcher {
+    handle: HCMNOTIFICATION,
+    callback: *mut Box<dyn FnMut(DeviceInstanceEvent) + Send>,
+}
+
+impl DeviceInstanceWatcher {
+    /// Register a new watcher for `instance_id`, delivering events to `callback` until this is
+    /// dropped
+    pub fn new<F: FnMut(DeviceInstanceEvent) + Send + 'static>(
+        instance_id: &WideCStr,
+        callback: F,
+    ) -> windows::core::Result<Self> {
+        let callback: Box<dyn FnMut(DeviceInstanceEvent) + Send> = Box::new(callback);
+        let callback = Box::into_raw(Box::new(callback));
+
+        let mut filter = CM_NOTIFY_FILTER::default();
+        filter.cbSize = mem::size_of::<CM_NOTIFY_FILTER>() as u32;
+        filter.FilterType = CM_NOTIFY_FILTER_TYPE_DEVICEINSTANCE;
+        unsafe {
+            let id = instance_id.as_slice();
+            let dest = &mut filter.u.DeviceInstance.InstanceId;
+            let len = id.len().min(dest.len() - 1);
+            dest[..len].copy_from_slice(&id[..len]);
+        }
+
+        let mut handle = HCMNOTIFICATION::default();
+        let result = cr_result(unsafe {
+            CM_Register_Notification(&filter, callback as *const c_void, Self::win32_callback, &mut handle)
+        });
+        match result {
+            Ok(()) => Ok(Self { handle, callback }),
+            Err(e) => {
+                drop(unsafe { Box::from_raw(callback) });
+                Err(e)
+            },
+        }
+    }
+
+    unsafe extern "system" fn win32_callback(
+        _handle: HCMNOTIFICATION,
+        context: *const c_void,
+        action: CM_NOTIFY_ACTION,
+        _event_data: *const CM_NOTIFY_EVENT_DATA,
+        _event_data_size: u32,
+    ) -> u32 {
+        if let Some(event) = DeviceInstanceEvent::from_win32(action) {
+            let callback = &mut *(context as *mut Box<dyn FnMut(DeviceInstanceEvent) + Send>);
+            callback(event);
+        }
+        0 // ERROR_SUCCESS
+    }
+}
+
+impl Drop for DeviceInstanceWatcher {
+    #[doc(alias = "CM_Unregister_Notification")]
+    fn drop(&mut self) {
+        let _ = unsafe { CM_Unregister_Notification(self.handle) };
+        drop(unsafe { Box::from_raw(self.callback) });
+    }
+}
+
+impl Debug for DeviceInstanceWatcher {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("DeviceInstanceWatcher").field("handle", &self.handle).finish()
+    }
+}
+
+unsafe impl Send for DeviceInstanceWatcher {}
+
+/// Block until no device installs are pending, or `timeout_ms` elapses
+///
+/// After receiving a [`MonitorEvent`]/[`DeviceInstanceEvent`], callers can use this to let the
+/// install queue settle before re-enumerating via [`InfoSet`](super::InfoSet)/[`DeviceQuery`
+/// ](super::DeviceQuery), instead of immediately racing a driver that is still being installed.
+///
+/// This is a wrapper around [`CMP_WaitNoPendingInstallEvents`][wraps], returning `true` if no
+/// installs are pending and `false` if `timeout_ms` elapsed while some were still pending.
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows-hardware/drivers/install/cmp-waitnopendinginstallevents
+#[doc(alias = "CMP_WaitNoPendingInstallEvents")]
+pub fn wait_no_pending_install_events(timeout_ms: u32) -> bool {
+    const WAIT_OBJECT_0: u32 = 0;
+    unsafe { CMP_WaitNoPendingInstallEvents(timeout_ms) == WAIT_OBJECT_0 }
+}
@@ -0,0 +1,597 @@
+//! Fully-decoded, type-tagged [`Property`](super::Property) values
+//!
+//! [`Property::to_value`](super::Property::to_value) is the entry point into this module.
+
+use {
+    super::{
+        property::{format_unix_time, systemtime_unix_parts},
+        value::{pod_to_bytes, std_to_filetime, wide_bytes},
+        Property, PropertyKey, PropertyType, PropertyTypeMod,
+    },
+    crate::win32::Guid,
+    std::{
+        fmt::{self, Debug, Display, Formatter},
+        time::SystemTime,
+    },
+    widestring::WideCString,
+    windows::Win32::Foundation::{NTSTATUS, WIN32_ERROR},
+};
+#[cfg(feature = "win32-extras")]
+use super::value::{filetime_to_systemtime, systemtime_to_variant};
+
+/// A fixed-point currency value, as used by [`PropertyType::Currency`]
+///
+/// The underlying value is an `i64`, scaled by [`Self::SCALE`] (four implied decimal places) —
+/// the same representation as a COM `CY`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "CY")]
+pub struct Currency(i64);
+
+impl Currency {
+    /// The factor by which [`self.raw()`](Self::raw) is scaled, i.e. four implied decimal places
+    pub const SCALE: i64 = 10_000;
+
+    /// The raw, scaled `i64` representation
+    pub const fn raw(&self) -> i64 {
+        self.0
+    }
+
+    /// This value, converted to a floating-point number of whole units
+    pub fn to_f64(&self) -> f64 {
+        self.0 as f64 / Self::SCALE as f64
+    }
+}
+
+impl From<i64> for Currency {
+    fn from(raw: i64) -> Self {
+        Self(raw)
+    }
+}
+
+impl From<Currency> for i64 {
+    fn from(value: Currency) -> Self {
+        value.0
+    }
+}
+
+impl Debug for Currency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Currency").field(&format_args!("{}", self)).finish()
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.0 < 0 {
+            f.write_str("-")?;
+        }
+        let digits = self.0.unsigned_abs().to_string();
+        const SCALE: usize = 4;
+        match digits.len() {
+            len if len > SCALE => {
+                let (whole, fract) = digits.split_at(len - SCALE);
+                write!(f, "{whole}.{fract}")
+            },
+            _ => write!(f, "0.{:0>width$}", digits, width = SCALE),
+        }
+    }
+}
+
+/// A 96-bit fixed-point value, as used by [`PropertyType::Decimal`]
+///
+/// Wraps the mantissa/scale/sign components of a
+/// [`DECIMAL`](windows::Win32::Foundation::DECIMAL) value.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[doc(alias = "DECIMAL")]
+pub struct Decimal {
+    mantissa: u128,
+    scale: u8,
+    negative: bool,
+}
+
+impl Decimal {
+    /// The unscaled 96-bit magnitude
+    pub const fn mantissa(&self) -> u128 {
+        self.mantissa
+    }
+
+    /// The number of implied decimal places in [`self.mantissa()`](Self::mantissa)
+    pub const fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Whether this value is negative
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// This value, converted to a floating-point number
+    ///
+    /// This may lose precision for mantissas that don't fit exactly in an `f64`.
+    pub fn to_f64(&self) -> f64 {
+        let value = self.mantissa as f64 / 10f64.powi(self.scale as i32);
+        match self.negative {
+            true => -value,
+            false => value,
+        }
+    }
+
+    /// Parse the 16-byte wire representation of a [`DECIMAL`](windows::Win32::Foundation::DECIMAL)
+    fn from_win32_bytes(data: [u8; 16]) -> Self {
+        let scale = data[2];
+        let negative = data[3] & 0x80 != 0;
+        let hi32 = u32::from_ne_bytes([data[4], data[5], data[6], data[7]]);
+        let lo32 = u32::from_ne_bytes([data[8], data[9], data[10], data[11]]);
+        let mid32 = u32::from_ne_bytes([data[12], data[13], data[14], data[15]]);
+        let mantissa = (hi32 as u128) << 64 | (mid32 as u128) << 32 | lo32 as u128;
+        Self { mantissa, scale, negative }
+    }
+
+    /// Build the 16-byte wire representation of a [`DECIMAL`](windows::Win32::Foundation::DECIMAL)
+    ///
+    /// Returns `None` if [`self.mantissa()`](Self::mantissa) doesn't fit in the 96 bits a
+    /// `DECIMAL` provides.
+    fn to_win32_bytes(&self) -> Option<[u8; 16]> {
+        if self.mantissa >> 96 != 0 {
+            return None
+        }
+        let hi32 = (self.mantissa >> 64) as u32;
+        let mid32 = (self.mantissa >> 32) as u32;
+        let lo32 = self.mantissa as u32;
+        let mut data = [0u8; 16];
+        data[2] = self.scale;
+        data[3] = match self.negative {
+            true => 0x80,
+            false => 0,
+        };
+        data[4..8].copy_from_slice(&hi32.to_ne_bytes());
+        data[8..12].copy_from_slice(&lo32.to_ne_bytes());
+        data[12..16].copy_from_slice(&mid32.to_ne_bytes());
+        Some(data)
+    }
+}
+
+impl Debug for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("Decimal").field(&format_args!("{}", self)).finish()
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        let digits = self.mantissa.to_string();
+        let scale = self.scale as usize;
+        match scale {
+            0 => f.write_str(&digits),
+            scale if digits.len() > scale => {
+                let (whole, fract) = digits.split_at(digits.len() - scale);
+                write!(f, "{whole}.{fract}")
+            },
+            scale => write!(f, "0.{:0>width$}", digits, width = scale),
+        }
+    }
+}
+
+/// A single, type-tagged value decoded from a [`Property`](super::Property)
+///
+/// Returned (once per element, for [array](PropertyTypeMod::Array)-typed properties) by
+/// [`Property::to_value`](super::Property::to_value); carries one variant per [`PropertyType`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PropertyValue {
+    /// [`PropertyType::Empty`]
+    Empty,
+    /// [`PropertyType::Null`]
+    Null,
+    /// [`PropertyType::Boolean`]
+    Boolean(bool),
+    /// [`PropertyType::Byte`]
+    Byte(u8),
+    /// [`PropertyType::SByte`]
+    SByte(i8),
+    /// [`PropertyType::Int16`]
+    Int16(i16),
+    /// [`PropertyType::UInt16`]
+    UInt16(u16),
+    /// [`PropertyType::Int32`]
+    Int32(i32),
+    /// [`PropertyType::UInt32`]
+    UInt32(u32),
+    /// [`PropertyType::Int64`]
+    Int64(i64),
+    /// [`PropertyType::UInt64`]
+    UInt64(u64),
+    /// [`PropertyType::Float`]
+    Float(f32),
+    /// [`PropertyType::Double`]
+    Double(f64),
+    /// [`PropertyType::Currency`]
+    Currency(Currency),
+    /// [`PropertyType::Date`]
+    Date(SystemTime),
+    /// [`PropertyType::FileTime`]
+    FileTime(SystemTime),
+    /// [`PropertyType::Decimal`]
+    Decimal(Decimal),
+    /// [`PropertyType::Guid`]
+    Guid(Guid),
+    /// [`PropertyType::PropertyKey`]
+    PropertyKey(PropertyKey),
+    /// [`PropertyType::PropertyType`]
+    PropertyType(PropertyTypeMod),
+    /// [`PropertyType::Error`]
+    Error(WIN32_ERROR),
+    /// [`PropertyType::NtStatus`]
+    NtStatus(NTSTATUS),
+    /// [`PropertyType::String`]
+    String(String),
+    /// [`PropertyType::StringIndirect`]
+    StringIndirect(String),
+    /// [`PropertyType::SecurityDescriptorString`], as
+    /// [SDDL](https://learn.microsoft.com/en-us/windows/win32/secauthz/security-descriptor-definition-language)
+    /// text
+    SecurityDescriptorString(String),
+    /// [`PropertyType::SecurityDescriptor`], as its raw `SECURITY_DESCRIPTOR` bytes
+    SecurityDescriptor(Vec<u8>),
+}
+
+impl PropertyValue {
+    pub(super) fn decode(value: &Property) -> Option<Self> {
+        Some(match value.type_.base_type() {
+            PropertyType::Empty => Self::Empty,
+            PropertyType::Null => Self::Null,
+            PropertyType::Boolean => Self::Boolean(value.get::<bool>()?),
+            PropertyType::Byte => Self::Byte(value.get::<u8>()?),
+            PropertyType::SByte => Self::SByte(value.get::<i8>()?),
+            PropertyType::Int16 => Self::Int16(value.get::<i16>()?),
+            PropertyType::UInt16 => Self::UInt16(value.get::<u16>()?),
+            PropertyType::Int32 => Self::Int32(value.get::<i32>()?),
+            PropertyType::UInt32 => Self::UInt32(value.get::<u32>()?),
+            PropertyType::Int64 => Self::Int64(value.get::<i64>()?),
+            PropertyType::UInt64 => Self::UInt64(value.get::<u64>()?),
+            PropertyType::Float => Self::Float(value.get::<f32>()?),
+            PropertyType::Double => Self::Double(value.get::<f64>()?),
+            PropertyType::Currency => Self::Currency(Currency::from(value.get::<i64>()?)),
+            PropertyType::Date => Self::Date(value.get::<SystemTime>()?),
+            PropertyType::FileTime => Self::FileTime(value.get::<SystemTime>()?),
+            PropertyType::Decimal => Self::Decimal(Decimal::from_win32_bytes(value.data().try_into().ok()?)),
+            PropertyType::Guid => Self::Guid(value.get::<Guid>()?),
+            PropertyType::PropertyKey => Self::PropertyKey(value.get::<PropertyKey>()?),
+            PropertyType::PropertyType => Self::PropertyType(value.get::<PropertyTypeMod>()?),
+            PropertyType::Error => Self::Error(value.get::<WIN32_ERROR>()?),
+            PropertyType::NtStatus => Self::NtStatus(value.get::<NTSTATUS>()?),
+            PropertyType::String => Self::String(value.get::<WideCString>()?.to_string_lossy()),
+            PropertyType::StringIndirect => Self::StringIndirect(value.get::<WideCString>()?.to_string_lossy()),
+            PropertyType::SecurityDescriptorString =>
+                Self::SecurityDescriptorString(value.get::<WideCString>()?.to_string_lossy()),
+            PropertyType::SecurityDescriptor => Self::SecurityDescriptor(value.data().to_vec()),
+        })
+    }
+
+    /// Encode this value as the raw byte representation of `ty`
+    ///
+    /// This is the inverse of [`Self::decode`]; used by
+    /// [`Property::from_value`](super::Property::from_value) to serialize a single element of a
+    /// [plain](PropertyTypeMod::Plain) or [array](PropertyTypeMod::Array)-typed property.
+    ///
+    /// Returns `None` if `self` isn't the variant that `ty` decodes to.
+    pub(super) fn encode(&self, ty: PropertyType) -> Option<Vec<u8>> {
+        match (self, ty) {
+            (Self::Empty, PropertyType::Empty) | (Self::Null, PropertyType::Null) => Some(Vec::new()),
+            (Self::Boolean(v), PropertyType::Boolean) => Some(vec![*v as u8]),
+            (Self::Byte(v), PropertyType::Byte) => Some(vec![*v]),
+            (Self::SByte(v), PropertyType::SByte) => Some(v.to_ne_bytes().to_vec()),
+            (Self::Int16(v), PropertyType::Int16) => Some(v.to_ne_bytes().to_vec()),
+            (Self::UInt16(v), PropertyType::UInt16) => Some(v.to_ne_bytes().to_vec()),
+            (Self::Int32(v), PropertyType::Int32) => Some(v.to_ne_bytes().to_vec()),
+            (Self::UInt32(v), PropertyType::UInt32) => Some(v.to_ne_bytes().to_vec()),
+            (Self::Int64(v), PropertyType::Int64) => Some(v.to_ne_bytes().to_vec()),
+            (Self::UInt64(v), PropertyType::UInt64) => Some(v.to_ne_bytes().to_vec()),
+            (Self::Float(v), PropertyType::Float) => Some(v.to_ne_bytes().to_vec()),
+            (Self::Double(v), PropertyType::Double) => Some(v.to_ne_bytes().to_vec()),
+            (Self::Currency(v), PropertyType::Currency) => Some(v.raw().to_ne_bytes().to_vec()),
+            (Self::FileTime(v), PropertyType::FileTime) => std_to_filetime(v).map(|ft| pod_to_bytes(&ft)),
+            #[cfg(feature = "win32-extras")]
+            (Self::Date(v), PropertyType::Date) => std_to_filetime(v)
+                .and_then(|ft| filetime_to_systemtime(&ft))
+                .and_then(|st| systemtime_to_variant(&st))
+                .map(|days| days.to_ne_bytes().to_vec()),
+            (Self::Decimal(v), PropertyType::Decimal) => v.to_win32_bytes().map(|data| data.to_vec()),
+            (Self::Guid(v), PropertyType::Guid) => Some(pod_to_bytes(v.win32_guid())),
+            (Self::PropertyKey(v), PropertyType::PropertyKey) => Some(pod_to_bytes(&v.into_win32())),
+            (Self::PropertyType(v), PropertyType::PropertyType) => Some(v.win32_devprop_type().to_ne_bytes().to_vec()),
+            (Self::Error(v), PropertyType::Error) => Some(v.0.to_ne_bytes().to_vec()),
+            (Self::NtStatus(v), PropertyType::NtStatus) => Some(v.0.to_ne_bytes().to_vec()),
+            (Self::String(v), PropertyType::String)
+            | (Self::StringIndirect(v), PropertyType::StringIndirect)
+            | (Self::SecurityDescriptorString(v), PropertyType::SecurityDescriptorString) => wide_bytes(v),
+            (Self::SecurityDescriptor(v), PropertyType::SecurityDescriptor) => Some(v.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PropertyValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Empty | Self::Null => Ok(()),
+            Self::Boolean(v) => write!(f, "{v:?}"),
+            Self::Byte(v) => write!(f, "{v}"),
+            Self::SByte(v) => write!(f, "{v}"),
+            Self::Int16(v) => write!(f, "{v}"),
+            Self::UInt16(v) => write!(f, "{v}"),
+            Self::Int32(v) => write!(f, "{v}"),
+            Self::UInt32(v) => write!(f, "{v}"),
+            Self::Int64(v) => write!(f, "{v}"),
+            Self::UInt64(v) => write!(f, "{v}"),
+            Self::Float(v) => write!(f, "{v}"),
+            Self::Double(v) => write!(f, "{v}"),
+            Self::Currency(v) => write!(f, "{v}"),
+            Self::Date(v) | Self::FileTime(v) => {
+                let (secs, nanos) = systemtime_unix_parts(v);
+                format_unix_time(f, secs, nanos)
+            },
+            Self::Decimal(v) => write!(f, "{v}"),
+            Self::Guid(v) => write!(f, "{v}"),
+            Self::PropertyKey(v) => write!(f, "{v}"),
+            Self::PropertyType(v) => write!(f, "{v}"),
+            Self::Error(v) => write!(f, "{v:?}"),
+            Self::NtStatus(v) => write!(f, "{v:?}"),
+            Self::String(v) | Self::StringIndirect(v) | Self::SecurityDescriptorString(v) => write!(f, "{v}"),
+            Self::SecurityDescriptor(v) => {
+                for b in v {
+                    write!(f, "{b:02x}")?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+/// Like [`PropertyTypeMod`], but carrying fully-[decoded](PropertyValue) values
+///
+/// Returned by [`Property::to_value`](super::Property::to_value).
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum PropertyValueMod {
+    /// A single decoded value
+    Plain(PropertyValue),
+    /// Every decoded element of an [array](PropertyTypeMod::Array)-typed property
+    Array(Vec<PropertyValue>),
+    /// Every string of a [list](PropertyTypeMod::List)-typed property
+    List(Vec<WideCString>),
+}
+
+impl PropertyValueMod {
+    /// Decode a value directly from its raw [type](PropertyTypeMod) and bytes
+    ///
+    /// Equivalent to `Property::new(type_, data).to_value()`; provided for callers that only have
+    /// a `(PropertyTypeMod, &[u8])` pair on hand (e.g. a generic property dumper walking raw
+    /// `DEVPROPERTY` buffers) without wanting to construct a [`Property`] of their own first.
+    pub fn decode(type_: PropertyTypeMod, data: &[u8]) -> Option<Self> {
+        Property::new(type_, data).to_value()
+    }
+}
+
+impl Display for PropertyValueMod {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Plain(value) => write!(f, "{value}"),
+            Self::Array(values) => {
+                f.write_str("[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            },
+            Self::List(strings) => {
+                f.write_str("[")?;
+                for (i, s) in strings.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", s.display())?;
+                }
+                f.write_str("]")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use {
+        super::{Currency, Decimal, PropertyValue, PropertyValueMod},
+        crate::device::{PropertyKey, PropertyTypeMod},
+        serde::{Deserialize, Deserializer, Serialize, Serializer},
+        std::time::SystemTime,
+        widestring::WideCString,
+    };
+
+    impl Serialize for Currency {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.raw().serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Currency {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            i64::deserialize(deserializer).map(Self::from)
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct DecimalRepr {
+        mantissa: u128,
+        scale: u8,
+        negative: bool,
+    }
+
+    impl Serialize for Decimal {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            DecimalRepr {
+                mantissa: self.mantissa,
+                scale: self.scale,
+                negative: self.negative,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Decimal {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let DecimalRepr { mantissa, scale, negative } = DecimalRepr::deserialize(deserializer)?;
+            Ok(Self { mantissa, scale, negative })
+        }
+    }
+
+    /// A serde-friendly mirror of [`PropertyValue`], substituting [`crate::win32`] wrapper types
+    /// that don't implement [`Serialize`]/[`Deserialize`] themselves with plain numeric fields
+    #[derive(Serialize, Deserialize)]
+    enum PropertyValueRepr {
+        Empty,
+        Null,
+        Boolean(bool),
+        Byte(u8),
+        SByte(i8),
+        Int16(i16),
+        UInt16(u16),
+        Int32(i32),
+        UInt32(u32),
+        Int64(i64),
+        UInt64(u64),
+        Float(f32),
+        Double(f64),
+        Currency(Currency),
+        Date(SystemTime),
+        FileTime(SystemTime),
+        Decimal(Decimal),
+        Guid(crate::win32::Guid),
+        PropertyKey(PropertyKey),
+        PropertyType(PropertyTypeMod),
+        Error(u32),
+        NtStatus(i32),
+        String(String),
+        StringIndirect(String),
+        SecurityDescriptorString(String),
+        SecurityDescriptor(Vec<u8>),
+    }
+
+    impl From<&PropertyValue> for PropertyValueRepr {
+        fn from(value: &PropertyValue) -> Self {
+            match *value {
+                PropertyValue::Empty => Self::Empty,
+                PropertyValue::Null => Self::Null,
+                PropertyValue::Boolean(v) => Self::Boolean(v),
+                PropertyValue::Byte(v) => Self::Byte(v),
+                PropertyValue::SByte(v) => Self::SByte(v),
+                PropertyValue::Int16(v) => Self::Int16(v),
+                PropertyValue::UInt16(v) => Self::UInt16(v),
+                PropertyValue::Int32(v) => Self::Int32(v),
+                PropertyValue::UInt32(v) => Self::UInt32(v),
+                PropertyValue::Int64(v) => Self::Int64(v),
+                PropertyValue::UInt64(v) => Self::UInt64(v),
+                PropertyValue::Float(v) => Self::Float(v),
+                PropertyValue::Double(v) => Self::Double(v),
+                PropertyValue::Currency(v) => Self::Currency(v),
+                PropertyValue::Date(v) => Self::Date(v),
+                PropertyValue::FileTime(v) => Self::FileTime(v),
+                PropertyValue::Decimal(v) => Self::Decimal(v),
+                PropertyValue::Guid(v) => Self::Guid(v),
+                PropertyValue::PropertyKey(v) => Self::PropertyKey(v),
+                PropertyValue::PropertyType(v) => Self::PropertyType(v),
+                PropertyValue::Error(v) => Self::Error(v.0),
+                PropertyValue::NtStatus(v) => Self::NtStatus(v.0),
+                PropertyValue::String(ref v) => Self::String(v.clone()),
+                PropertyValue::StringIndirect(ref v) => Self::StringIndirect(v.clone()),
+                PropertyValue::SecurityDescriptorString(ref v) => Self::SecurityDescriptorString(v.clone()),
+                PropertyValue::SecurityDescriptor(ref v) => Self::SecurityDescriptor(v.clone()),
+            }
+        }
+    }
+
+    impl From<PropertyValueRepr> for PropertyValue {
+        fn from(value: PropertyValueRepr) -> Self {
+            match value {
+                PropertyValueRepr::Empty => Self::Empty,
+                PropertyValueRepr::Null => Self::Null,
+                PropertyValueRepr::Boolean(v) => Self::Boolean(v),
+                PropertyValueRepr::Byte(v) => Self::Byte(v),
+                PropertyValueRepr::SByte(v) => Self::SByte(v),
+                PropertyValueRepr::Int16(v) => Self::Int16(v),
+                PropertyValueRepr::UInt16(v) => Self::UInt16(v),
+                PropertyValueRepr::Int32(v) => Self::Int32(v),
+                PropertyValueRepr::UInt32(v) => Self::UInt32(v),
+                PropertyValueRepr::Int64(v) => Self::Int64(v),
+                PropertyValueRepr::UInt64(v) => Self::UInt64(v),
+                PropertyValueRepr::Float(v) => Self::Float(v),
+                PropertyValueRepr::Double(v) => Self::Double(v),
+                PropertyValueRepr::Currency(v) => Self::Currency(v),
+                PropertyValueRepr::Date(v) => Self::Date(v),
+                PropertyValueRepr::FileTime(v) => Self::FileTime(v),
+                PropertyValueRepr::Decimal(v) => Self::Decimal(v),
+                PropertyValueRepr::Guid(v) => Self::Guid(v),
+                PropertyValueRepr::PropertyKey(v) => Self::PropertyKey(v),
+                PropertyValueRepr::PropertyType(v) => Self::PropertyType(v),
+                PropertyValueRepr::Error(v) => Self::Error(windows::Win32::Foundation::WIN32_ERROR(v)),
+                PropertyValueRepr::NtStatus(v) => Self::NtStatus(windows::Win32::Foundation::NTSTATUS(v)),
+                PropertyValueRepr::String(v) => Self::String(v),
+                PropertyValueRepr::StringIndirect(v) => Self::StringIndirect(v),
+                PropertyValueRepr::SecurityDescriptorString(v) => Self::SecurityDescriptorString(v),
+                PropertyValueRepr::SecurityDescriptor(v) => Self::SecurityDescriptor(v),
+            }
+        }
+    }
+
+    impl Serialize for PropertyValue {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PropertyValueRepr::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PropertyValue {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            PropertyValueRepr::deserialize(deserializer).map(Into::into)
+        }
+    }
+
+    /// A serde-friendly mirror of [`PropertyValueMod`], representing
+    /// [`List`](PropertyValueMod::List) strings as `String`, converting invalid UTF-16 lossily
+    #[derive(Serialize, Deserialize)]
+    enum PropertyValueModRepr {
+        Plain(PropertyValue),
+        Array(Vec<PropertyValue>),
+        List(Vec<String>),
+    }
+
+    impl Serialize for PropertyValueMod {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Plain(v) => PropertyValueModRepr::Plain(v.clone()),
+                Self::Array(v) => PropertyValueModRepr::Array(v.clone()),
+                Self::List(v) => PropertyValueModRepr::List(v.iter().map(|s| s.to_string_lossy()).collect()),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PropertyValueMod {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            Ok(match PropertyValueModRepr::deserialize(deserializer)? {
+                PropertyValueModRepr::Plain(v) => Self::Plain(v),
+                PropertyValueModRepr::Array(v) => Self::Array(v),
+                PropertyValueModRepr::List(v) => Self::List(
+                    v.into_iter()
+                        .map(|s| WideCString::from_str(s).map_err(serde::de::Error::custom))
+                        .collect::<Result<_, _>>()?,
+                ),
+            })
+        }
+    }
+}
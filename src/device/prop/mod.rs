@@ -1,4 +1,5 @@
 pub use self::{
+    decoded::{Currency, Decimal, PropertyValue, PropertyValueMod},
     property::Property,
     ty::{PropertyType, PropertyTypeMod},
     value::InfoPropertyValue,
@@ -16,6 +17,7 @@ use {
     windows::Win32::Devices::Properties::{self, DEVPROPKEY},
 };
 
+pub(crate) mod decoded;
 pub(crate) mod property;
 pub(crate) mod ty;
 pub(crate) mod value;
@@ -48,6 +50,15 @@ impl PropertyKey {
     pub const fn id(&self) -> u32 {
         self.info.pid
     }
+
+    /// Parse the `"{fmtid}\pid"` textual representation produced by [`Display`]
+    pub fn parse(s: &str) -> Option<Self> {
+        let (fmtid, pid) = s.rsplit_once('\\')?;
+        Some(Self::from_win32(DEVPROPKEY {
+            fmtid: Guid::parse(fmtid)?.into(),
+            pid: pid.parse().ok()?,
+        }))
+    }
 }
 
 #[allow(missing_docs)]
@@ -272,3 +283,31 @@ devpkeys! {
     pub const DEVICE_UI_NUMBER_DESC_FORMAT = DEVPKEY_Device_UINumberDescFormat;
     pub const DEVICE_UPPER_FILTERS = DEVPKEY_Device_UpperFilters;
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl serde::Serialize for PropertyKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for PropertyKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = PropertyKey;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("a property key string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<PropertyKey, E> {
+                PropertyKey::parse(v).ok_or_else(|| E::custom(format_args!("invalid property key: {v:?}")))
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
@@ -1,5 +1,5 @@
 use {
-    super::{InfoPropertyValue, PropertyKey, PropertyType, PropertyTypeMod},
+    super::{Currency, InfoPropertyValue, PropertyKey, PropertyType, PropertyTypeMod, PropertyValue, PropertyValueMod},
     crate::win32::{transmute_slice, transmute_vec, Guid},
     std::{
         any::{Any, TypeId},
@@ -11,7 +11,7 @@ use {
     widestring::{WideCStr, WideCString},
     windows::{
         core::HRESULT,
-        Win32::Foundation::{FILETIME, NTSTATUS, SYSTEMTIME, WIN32_ERROR},
+        Win32::Foundation::{DECIMAL, FILETIME, NTSTATUS, SYSTEMTIME, WIN32_ERROR},
     },
 };
 
@@ -117,6 +117,11 @@ impl<'a> Property<'a> {
     /// The range of this value is [i64::MIN]..=[u64::MAX].
     /// [Booleans](PropertyType::Boolean) are converted to either `0` or `1`.
     /// Floating point values are not supported, use [`self.to_f64()`] for those instead.
+    /// [`Decimal`](PropertyType::Decimal) isn't a plain integer and has no meaningful
+    /// truncation to one; use [`self.to_value()`](Self::to_value) for it instead.
+    /// [`Currency`](PropertyType::Currency) is returned as its raw, scaled integer
+    /// representation — use [`self.to_decimal()`](Self::to_decimal) to get its implied
+    /// decimal point back.
     pub fn to_i128(&self) -> Option<i128> {
         match self.type_.base_type() {
             PropertyType::Boolean => self.get::<bool>().map(Into::into),
@@ -127,11 +132,100 @@ impl<'a> Property<'a> {
             PropertyType::UInt32 => self.get::<u32>().map(Into::into),
             PropertyType::Int32 => self.get::<i32>().map(Into::into),
             PropertyType::UInt64 => self.get::<u64>().map(Into::into),
-            PropertyType::Int64 | PropertyType::Decimal | PropertyType::Currency => self.get::<i64>().map(Into::into),
+            PropertyType::Int64 | PropertyType::Currency => self.get::<i64>().map(Into::into),
             _ => None,
         }
     }
 
+    /// Get the value of a [`Decimal`](PropertyType::Decimal) or
+    /// [`Currency`](PropertyType::Currency) property without losing its fractional scale
+    ///
+    /// Returns the signed mantissa and its scale, such that the real value is
+    /// `mantissa / 10^scale`. Unlike [`self.to_i128()`](Self::to_i128), which truncates
+    /// straight through the raw integer representation, this preserves the implied decimal point.
+    pub fn to_decimal(&self) -> Option<(i128, u8)> {
+        match self.type_.base_type() {
+            // Currency::SCALE is 10_000, i.e. four implied decimal places
+            PropertyType::Currency => self.get::<i64>().map(|raw| (raw as i128, 4)),
+            PropertyType::Decimal => PropertyValue::decode(self).and_then(|v| match v {
+                PropertyValue::Decimal(d) => {
+                    let mantissa = d.mantissa() as i128;
+                    Some((if d.is_negative() { -mantissa } else { mantissa }, d.scale()))
+                },
+                _ => None,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Decode this property into a fully-typed [`PropertyValueMod`]
+    ///
+    /// Unlike [`self.get()`](Self::get), this covers every [`PropertyType`], including ones
+    /// with no dedicated accessor such as [`Decimal`](PropertyType::Decimal) or
+    /// [`SecurityDescriptorString`](PropertyType::SecurityDescriptorString).
+    ///
+    /// Returns `None` if [`self.data`](Self::data)'s length doesn't match what
+    /// [`self.type_`](Self::type_) expects — e.g. a fixed-size [`Plain`](PropertyTypeMod::Plain)
+    /// value of the wrong length, or an [`Array`](PropertyTypeMod::Array) whose buffer isn't a
+    /// multiple of its element size.
+    pub fn to_value(&self) -> Option<PropertyValueMod> {
+        match self.type_ {
+            PropertyTypeMod::Plain(_) => PropertyValue::decode(self).map(PropertyValueMod::Plain),
+            PropertyTypeMod::Array(ty) => iter_array(ty, &self.data)
+                .and_then(|values| values.map(|v| PropertyValue::decode(&v)).collect::<Option<Vec<_>>>())
+                .map(PropertyValueMod::Array),
+            PropertyTypeMod::List(ty) if ty.is_string() => self.get::<Vec<WideCString>>().map(PropertyValueMod::List),
+            PropertyTypeMod::List(_) => None,
+        }
+    }
+
+    /// Encode a decoded [`PropertyValueMod`] into a fresh, owned [`Property`]
+    ///
+    /// This is the inverse of [`self.to_value()`](Self::to_value); its result carries the
+    /// `win32_devprop_type()` DWORD (via [`self.type_`](Self::type_)) and byte buffer expected by
+    /// `SetupDiSetDeviceProperty`/`CM_Set_DevNode_Property`.
+    ///
+    /// Returns `None` if `type_`/`value` don't agree — `type_` isn't [valid](PropertyTypeMod::is_valid)
+    /// for its base type (e.g. an [`Array`](PropertyTypeMod::Array) of a variable-length base, or a
+    /// [`List`](PropertyTypeMod::List) of a non-[string](PropertyType::is_string) base), or `value`'s
+    /// shape ([`Plain`](PropertyValueMod::Plain)/[`Array`](PropertyValueMod::Array)/[`List`](PropertyValueMod::List))
+    /// doesn't match `type_`.
+    pub fn from_value(type_: PropertyTypeMod, value: &PropertyValueMod) -> Option<Property<'static>> {
+        if !type_.is_valid() {
+            return None
+        }
+        let data = match (type_, value) {
+            (PropertyTypeMod::Plain(ty), PropertyValueMod::Plain(value)) => value.encode(ty)?,
+            (PropertyTypeMod::Array(ty), PropertyValueMod::Array(values)) => values
+                .iter()
+                .map(|value| value.encode(ty))
+                .collect::<Option<Vec<_>>>()?
+                .concat(),
+            (PropertyTypeMod::List(ty), PropertyValueMod::List(strings)) if ty.is_string() => {
+                let mut data = strings
+                    .iter()
+                    .flat_map(|s| s.as_slice_with_nul().iter().flat_map(|c| c.to_ne_bytes()))
+                    .collect::<Vec<_>>();
+                data.extend(0u16.to_ne_bytes());
+                data
+            },
+            _ => return None,
+        };
+        Some(Property::new(type_, data))
+    }
+
+    /// Build a [`Property`] straight from a typed Rust value
+    ///
+    /// This is the inverse of [`self.get()`](Self::get); it's a thin wrapper around
+    /// [`T::to_bytes`](InfoPropertyValue::to_bytes), so it supports whatever that trait does —
+    /// primitives, [`Guid`], `WIN32_ERROR`/`NTSTATUS`, [`WideCStr`]/string, arrays, and string lists.
+    /// An `HRESULT` is not encoded: its `to_bytes` always returns `None`.
+    ///
+    /// Returns `None` if `value` doesn't [support](InfoPropertyValue::supports_type) `type_`.
+    pub fn encode<T: for<'v> InfoPropertyValue<'v>>(type_: PropertyTypeMod, value: &T) -> Option<Property<'static>> {
+        value.to_bytes(type_).map(|data| Self::new(type_, data))
+    }
+
     /// Get the value of a [floating-point](PropertyType::is_float) property
     pub fn to_f64(&self) -> Option<f64> {
         match self.type_.base_type() {
@@ -174,8 +268,14 @@ impl<'a> Property<'a> {
                         return self.get::<i64>().map(|ts| f(&ts, Ok(&ts)))
                     }
                 }),
-                #[cfg(feature = "win32-extras")]
-                PropertyType::Currency => self.get::<crate::win32::CY_0>().map(|v| f(&v, Err(&v))),
+                PropertyType::Currency => self.get::<i64>().map(|raw| {
+                    let currency = Currency::from(raw);
+                    f(&currency, Ok(&currency))
+                }),
+                PropertyType::Decimal => PropertyValue::decode(self).and_then(|v| match v {
+                    PropertyValue::Decimal(d) => Some(f(&d, Ok(&d))),
+                    _ => None,
+                }),
                 PropertyType::Guid => self.get::<Guid>().map(|v| f(&v, Ok(&v))),
                 PropertyType::PropertyKey => self.get::<PropertyKey>().map(|v| f(&v, Ok(&v))),
                 PropertyType::Error | PropertyType::NtStatus => self.get::<HRESULT>().map(|v| f(&v, Ok(&v))),
@@ -190,6 +290,16 @@ impl<'a> Property<'a> {
     #[inline(always)]
     fn assert_result<'v, T: InfoPropertyValue<'v>>(&self, res: Option<T>) -> Option<T> {
         debug_assert!(res.is_none() || T::supports_type(self.type_));
+        #[cfg(feature = "tracing")]
+        if res.is_none() {
+            tracing::debug!(
+                requested = std::any::type_name::<T>(),
+                expected = ?T::TYPE,
+                actual = ?self.type_,
+                data_len = self.data.len(),
+                "property value decode returned None",
+            );
+        }
         res
     }
 }
@@ -234,7 +344,7 @@ impl<'a> Display for Property<'a> {
                     } else if let Some(time) = any.downcast_ref::<SYSTEMTIME>() {
                         Some(write!(
                             f,
-                            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:02}",
+                            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}",
                             time.wYear,
                             time.wMonth,
                             time.wDay,
@@ -244,15 +354,14 @@ impl<'a> Display for Property<'a> {
                             time.wMilliseconds
                         ))
                     } else if let Some(time) = any.downcast_ref::<FILETIME>() {
-                        // TODO: format this literally any other way
-                        Some(write!(f, "{:08x}{:08x}", time.dwHighDateTime, time.dwLowDateTime))
+                        let (secs, nanos) = filetime_unix_parts(time);
+                        Some(format_unix_time(f, secs, nanos))
+                    } else if let Some(time) = any.downcast_ref::<SystemTime>() {
+                        let (secs, nanos) = systemtime_unix_parts(time);
+                        Some(format_unix_time(f, secs, nanos))
                     } else {
-                        #[cfg(feature = "win32-extras")]
-                        if let Some(cy) = any.downcast_ref::<crate::win32::CY_0>() {
-                            return Some(write!(f, "{}.{}", cy.Hi, cy.Lo))
-                        }
                         None
-                    }, // TODO: if let Some(time) = any.downcast_ref::<SystemTime>()
+                    },
             })
             .flatten();
 
@@ -289,6 +398,64 @@ impl<'a> Display for Property<'a> {
     }
 }
 
+/// Number of 100ns ticks between the [`FILETIME`] epoch (1601-01-01) and the Unix epoch
+const FILETIME_UNIX_EPOCH_TICKS: i64 = 116_444_736_000_000_000;
+
+/// Split a [`FILETIME`] into signed seconds and sub-second nanoseconds since the Unix epoch
+fn filetime_unix_parts(time: &FILETIME) -> (i64, u32) {
+    let ticks = ((time.dwHighDateTime as u64) << 32 | time.dwLowDateTime as u64) as i64;
+    let unix_ticks = ticks - FILETIME_UNIX_EPOCH_TICKS;
+    (unix_ticks.div_euclid(10_000_000), unix_ticks.rem_euclid(10_000_000) as u32 * 100)
+}
+
+/// Split a [`SystemTime`] into signed seconds and sub-second nanoseconds since the Unix epoch
+pub(super) fn systemtime_unix_parts(time: &SystemTime) -> (i64, u32) {
+    match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(d) => (d.as_secs() as i64, d.subsec_nanos()),
+        Err(e) => {
+            let d = e.duration();
+            match d.subsec_nanos() {
+                0 => (-(d.as_secs() as i64), 0),
+                nanos => (-(d.as_secs() as i64) - 1, 1_000_000_000 - nanos),
+            }
+        },
+    }
+}
+
+/// Format a Unix timestamp the same `YYYY-MM-DDThh:mm:ss.sss` way the [`SYSTEMTIME`] branch above does
+///
+/// The civil date conversion is [Howard Hinnant's `civil_from_days`][civil_from_days].
+///
+/// [civil_from_days]: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+pub(super) fn format_unix_time(f: &mut Formatter, secs: i64, nanos: u32) -> fmt::Result {
+    let days = secs.div_euclid(86400);
+    let time_of_day = secs.rem_euclid(86400);
+    let (hour, minute, second) = (time_of_day / 3600, time_of_day % 3600 / 60, time_of_day % 60);
+
+    let z = days + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = y + if month <= 2 { 1 } else { 0 };
+
+    write!(
+        f,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}",
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanos / 1_000_000
+    )
+}
+
 impl PropertyType {
     /// The byte size of types that represent plain old data such as a struct or primitive
     ///
@@ -298,7 +465,8 @@ impl PropertyType {
             Self::Boolean | Self::Byte | Self::SByte => mem::size_of::<u8>(),
             Self::Int16 | Self::UInt16 => mem::size_of::<u16>(),
             Self::Int32 | Self::UInt32 => mem::size_of::<u32>(),
-            Self::Int64 | Self::UInt64 | Self::FileTime | Self::Decimal | Self::Currency => mem::size_of::<u64>(),
+            Self::Int64 | Self::UInt64 | Self::FileTime | Self::Currency => mem::size_of::<u64>(),
+            Self::Decimal => mem::size_of::<DECIMAL>(),
             Self::Double | Self::Date => mem::size_of::<f64>(),
             Self::Float => mem::size_of::<f32>(),
             Self::PropertyKey => mem::size_of::<PropertyKey>(),
@@ -369,3 +537,105 @@ pub(crate) fn iter_string_list<'a>(
         })
     })
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+mod serde_support {
+    use {
+        super::{
+            format_unix_time, systemtime_unix_parts, Currency, Property, PropertyKey, PropertyType, PropertyTypeMod,
+            PropertyValue,
+        },
+        crate::win32::Guid,
+        serde::ser::{Error, Serialize, SerializeSeq, SerializeStruct, Serializer},
+        std::{
+            fmt::{self, Display, Formatter},
+            time::SystemTime,
+        },
+        widestring::WideCStr,
+        windows::core::HRESULT,
+    };
+
+    /// Displays as the `YYYY-MM-DDThh:mm:ss.sss` rendering [`format_unix_time`] produces
+    struct Iso8601(i64, u32);
+
+    impl Display for Iso8601 {
+        fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+            format_unix_time(f, self.0, self.1)
+        }
+    }
+
+    impl<'a> Serialize for Property<'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut out = serializer.serialize_struct("Property", 2)?;
+            out.serialize_field("type_", &self.type_)?;
+            out.serialize_field("value", &ValueRef(self))?;
+            out.end()
+        }
+    }
+
+    /// Serializes [`Property`]'s decoded value structurally, rather than as raw bytes
+    struct ValueRef<'p, 'a>(&'p Property<'a>);
+
+    impl<'p, 'a> Serialize for ValueRef<'p, 'a> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let property = self.0;
+            match property.type_ {
+                PropertyTypeMod::Plain(ty) => serialize_plain(property, ty, serializer),
+                PropertyTypeMod::Array(_) => {
+                    let values = property
+                        .values()
+                        .ok_or_else(|| S::Error::custom("malformed array property data"))?;
+                    let mut seq = serializer.serialize_seq(None)?;
+                    for value in values {
+                        seq.serialize_element(&value)?;
+                    }
+                    seq.end()
+                },
+                PropertyTypeMod::List(_) => {
+                    let strings = property
+                        .win32_string_list()
+                        .ok_or_else(|| S::Error::custom("malformed string list property data"))?;
+                    let mut seq = serializer.serialize_seq(None)?;
+                    for s in strings {
+                        seq.serialize_element(&s.to_string_lossy())?;
+                    }
+                    seq.end()
+                },
+            }
+        }
+    }
+
+    fn serialize_plain<S: Serializer>(property: &Property, ty: PropertyType, serializer: S) -> Result<S::Ok, S::Error> {
+        match ty {
+            PropertyType::Boolean => property.get::<bool>().map(|v| v.serialize(serializer)),
+            PropertyType::Byte => property.get::<u8>().map(|v| v.serialize(serializer)),
+            PropertyType::SByte => property.get::<i8>().map(|v| v.serialize(serializer)),
+            PropertyType::UInt16 => property.get::<u16>().map(|v| v.serialize(serializer)),
+            PropertyType::Int16 => property.get::<i16>().map(|v| v.serialize(serializer)),
+            PropertyType::UInt32 => property.get::<u32>().map(|v| v.serialize(serializer)),
+            PropertyType::Int32 => property.get::<i32>().map(|v| v.serialize(serializer)),
+            PropertyType::UInt64 => property.get::<u64>().map(|v| v.serialize(serializer)),
+            PropertyType::Int64 => property.get::<i64>().map(|v| v.serialize(serializer)),
+            PropertyType::Float => property.get::<f32>().map(|v| v.serialize(serializer)),
+            PropertyType::Double => property.get::<f64>().map(|v| v.serialize(serializer)),
+            PropertyType::Currency => property.get::<i64>().map(|raw| Currency::from(raw).serialize(serializer)),
+            PropertyType::Decimal => PropertyValue::decode(property).and_then(|v| match v {
+                PropertyValue::Decimal(d) => Some(d.serialize(serializer)),
+                _ => None,
+            }),
+            PropertyType::Guid => property.get::<Guid>().map(|v| serializer.collect_str(&v)),
+            PropertyType::PropertyKey => property.get::<PropertyKey>().map(|v| v.serialize(serializer)),
+            PropertyType::Error | PropertyType::NtStatus =>
+                property.get::<HRESULT>().map(|v| serializer.collect_str(&v)),
+            PropertyType::FileTime | PropertyType::Date => property.get::<SystemTime>().map(|time| {
+                let (secs, nanos) = systemtime_unix_parts(&time);
+                serializer.collect_str(&Iso8601(secs, nanos))
+            }),
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                property.borrow::<WideCStr>().map(|v| serializer.collect_str(&v.display())),
+            _ => None,
+        }
+        .unwrap_or_else(|| Err(S::Error::custom(format_args!("unsupported property type {ty:?} for serialization"))))
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(doc)]
 use windows::Win32;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use {
     crate::win32::win32_error,
     std::{
@@ -154,9 +156,10 @@ impl PropertyType {
     /// This indicates that [`Property::to_i128`](super::Property::to_i128)
     /// can return a value for this type.
     ///
-    /// Aside from the obvious types, [`Boolean`](Self::Boolean) is included,
-    /// alongside the fixed-point number types [`Decimal`](Self::Decimal) and
-    /// [`Currency`](Self::Currency).
+    /// Aside from the obvious types, [`Boolean`](Self::Boolean) is included, alongside the
+    /// fixed-point [`Currency`](Self::Currency) type. [`Decimal`](Self::Decimal) is excluded, as
+    /// its 96-bit mantissa and scale don't losslessly truncate to a plain integer; decode it via
+    /// [`Property::to_value`](super::Property::to_value) instead.
     pub const fn is_int(&self) -> bool {
         match self {
             Self::Byte
@@ -167,7 +170,6 @@ impl PropertyType {
             | Self::UInt32
             | Self::Int64
             | Self::UInt64
-            | Self::Decimal
             | Self::Currency
             | Self::Boolean => true,
             _ => false,
@@ -207,6 +209,39 @@ impl PropertyType {
             false => None,
         }
     }
+
+    /// Parse the `DEVPROP_TYPE_*` spelling produced by [`Display`]
+    pub fn parse_win32(s: &str) -> Option<Self> {
+        Some(match s {
+            "DEVPROP_TYPE_BOOLEAN" => Self::Boolean,
+            "DEVPROP_TYPE_BYTE" => Self::Byte,
+            "DEVPROP_TYPE_CURRENCY" => Self::Currency,
+            "DEVPROP_TYPE_DATE" => Self::Date,
+            "DEVPROP_TYPE_DECIMAL" => Self::Decimal,
+            "DEVPROP_TYPE_DEVPROPKEY" => Self::PropertyKey,
+            "DEVPROP_TYPE_DEVPROPTYPE" => Self::PropertyType,
+            "DEVPROP_TYPE_DOUBLE" => Self::Double,
+            "DEVPROP_TYPE_EMPTY" => Self::Empty,
+            "DEVPROP_TYPE_ERROR" => Self::Error,
+            "DEVPROP_TYPE_FILETIME" => Self::FileTime,
+            "DEVPROP_TYPE_FLOAT" => Self::Float,
+            "DEVPROP_TYPE_GUID" => Self::Guid,
+            "DEVPROP_TYPE_INT16" => Self::Int16,
+            "DEVPROP_TYPE_INT32" => Self::Int32,
+            "DEVPROP_TYPE_INT64" => Self::Int64,
+            "DEVPROP_TYPE_NTSTATUS" => Self::NtStatus,
+            "DEVPROP_TYPE_NULL" => Self::Null,
+            "DEVPROP_TYPE_SBYTE" => Self::SByte,
+            "DEVPROP_TYPE_SECURITY_DESCRIPTOR" => Self::SecurityDescriptor,
+            "DEVPROP_TYPE_SECURITY_DESCRIPTOR_STRING" => Self::SecurityDescriptorString,
+            "DEVPROP_TYPE_STRING" => Self::String,
+            "DEVPROP_TYPE_STRING_INDIRECT" => Self::StringIndirect,
+            "DEVPROP_TYPE_UINT16" => Self::UInt16,
+            "DEVPROP_TYPE_UINT32" => Self::UInt32,
+            "DEVPROP_TYPE_UINT64" => Self::UInt64,
+            _ => return None,
+        })
+    }
 }
 
 #[allow(missing_docs)]
@@ -414,3 +449,80 @@ impl TryFrom<u32> for PropertyTypeMod {
         Self::try_from_win32(ty)
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl Serialize for PropertyType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PropertyType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PropertyType;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("a DEVPROP_TYPE_* name")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<PropertyType, E> {
+                PropertyType::parse_win32(v).ok_or_else(|| E::custom(format_args!("unknown DEVPROP_TYPE: {v:?}")))
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+/// A serde-friendly mirror of [`PropertyTypeMod`] that keeps the array/list distinction as an
+/// explicit tag, rather than [`PropertyTypeMod`]'s [`Display`] spelling which renders both the
+/// same way
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+enum PropertyTypeModRepr {
+    Plain(PropertyType),
+    Array(PropertyType),
+    List(PropertyType),
+}
+
+#[cfg(feature = "serde")]
+impl From<PropertyTypeMod> for PropertyTypeModRepr {
+    fn from(ty: PropertyTypeMod) -> Self {
+        match ty {
+            PropertyTypeMod::Plain(ty) => Self::Plain(ty),
+            PropertyTypeMod::Array(ty) => Self::Array(ty),
+            PropertyTypeMod::List(ty) => Self::List(ty),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<PropertyTypeModRepr> for PropertyTypeMod {
+    fn from(ty: PropertyTypeModRepr) -> Self {
+        match ty {
+            PropertyTypeModRepr::Plain(ty) => Self::Plain(ty),
+            PropertyTypeModRepr::Array(ty) => Self::Array(ty),
+            PropertyTypeModRepr::List(ty) => Self::List(ty),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl Serialize for PropertyTypeMod {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PropertyTypeModRepr::from(*self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl<'de> Deserialize<'de> for PropertyTypeMod {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        PropertyTypeModRepr::deserialize(deserializer).map(Into::into)
+    }
+}
@@ -61,20 +61,60 @@ pub trait InfoPropertyValue<'a>: Sized {
     fn supports_type(type_: PropertyTypeMod) -> bool {
         type_ == Self::TYPE
     }
+
+    /// Encode `self` into the raw bytes a [plain](PropertyTypeMod::Plain) property of `type_` expects
+    ///
+    /// This is the inverse of [`get_plain()`](Self::get_plain); used to build the raw
+    /// `DEVPROPERTY`/property byte buffer backing a `SetupDiSetDeviceProperty`-style write.
+    ///
+    /// Properly behaving implementations shall return `None` if `type_` is
+    /// [unsupported](InfoPropertyValue::supports_type) - even if `self` could otherwise be encoded.
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>>;
+
+    /// Support encoding `self` as a [modified type](PropertyTypeMod)
+    ///
+    /// ## Default implementation
+    ///
+    /// This just proxies out to [`to_bytes_plain()`](Self::to_bytes_plain) if `type_`
+    /// is [plain](PropertyTypeMod::Plain).
+    fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyTypeMod::Plain(ty) => self.to_bytes_plain(ty),
+            _ => None,
+        }
+    }
+}
+
+/// Copy the bytes of a POD value, as [`size_of::<T>()`](mem::size_of) native-endian bytes
+pub(super) fn pod_to_bytes<T: Copy>(value: &T) -> Vec<u8> {
+    unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) }.to_vec()
+}
+
+/// Encode a [`WideCStr`], including its trailing NUL, as native-endian bytes
+fn widecstr_to_bytes(s: &WideCStr) -> Vec<u8> {
+    s.as_slice_with_nul().iter().flat_map(|c| c.to_ne_bytes()).collect()
+}
+
+/// Encode `s` as a NUL-terminated [`WideCStr`]'s native-endian bytes
+///
+/// Returns `None` if `s` contains an interior NUL.
+pub(super) fn wide_bytes(s: &str) -> Option<Vec<u8>> {
+    let s = WideCString::from_str(s).ok()?;
+    Some(widecstr_to_bytes(&s))
 }
 
 macro_rules! impl_value {
-    (@primitives $($(#[$attr:meta])* $ty:ty = $pty:path, ($pat:pat => $opt:expr),)*) => {
+    (@primitives $($(#[$attr:meta])* $ty:ty = $pty:path, ($pat:pat => $opt:expr), $enc:expr,)*) => {
         $(
-            impl_value! { @primitive $(#[$attr])* $ty = $pty{$pty}, ($pat => $opt) }
+            impl_value! { @primitive $(#[$attr])* $ty = $pty{$pty}, ($pat => $opt), $enc }
         )*
     };
-    (@primitives $($(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, ($pat:pat => $opt:expr),)*) => {
+    (@primitives $($(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, ($pat:pat => $opt:expr), $enc:expr,)*) => {
         $(
-            impl_value! { @primitive $(#[$attr])* $ty = $pty{$ptypat}, ($pat => $opt) }
+            impl_value! { @primitive $(#[$attr])* $ty = $pty{$ptypat}, ($pat => $opt), $enc }
         )*
     };
-    (@primitive $(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, ($pat:pat => $opt:expr)) => {
+    (@primitive $(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, ($pat:pat => $opt:expr), $enc:expr) => {
         $(#[$attr])*
         impl<'a> InfoPropertyValue<'a> for $ty {
             const TYPE: PropertyTypeMod = PropertyTypeMod::Plain($pty);
@@ -96,10 +136,17 @@ macro_rules! impl_value {
                     _ => None,
                 }
             }
+
+            fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+                match type_ {
+                    $ptypat => Some(($enc)(self)),
+                    _ => None,
+                }
+            }
         }
 
-        impl_value! { @ref $ty = $pty{$ptypat}, $pat }
-        impl_value! { @cow $ty = $pty{$ptypat}, $pat }
+        impl_value! { @ref $ty = $pty{$ptypat}, $pat, $enc }
+        impl_value! { @cow $ty = $pty{$ptypat}, $pat, $enc }
     };
     (@pods $($(#[$attr:meta])* $ty:ty = $pty:path,)*) => {
         $(
@@ -114,13 +161,20 @@ macro_rules! impl_value {
                         _ => None,
                     }
                 }
+
+                fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+                    match type_ {
+                        $pty => Some(pod_to_bytes(self)),
+                        _ => None,
+                    }
+                }
             }
 
-            impl_value! { @ref $(#[$attr])* $ty = $pty{$pty}, _ }
-            impl_value! { @cow $(#[$attr])* $ty = $pty{$pty}, _ }
+            impl_value! { @ref $(#[$attr])* $ty = $pty{$pty}, _, |v: &$ty| pod_to_bytes(v) }
+            impl_value! { @cow $(#[$attr])* $ty = $pty{$pty}, _, |v: &$ty| pod_to_bytes(v) }
         )*
     };
-    (@ref $(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, $data:pat) => {
+    (@ref $(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, $data:pat, $enc:expr) => {
         $(#[$attr])*
         impl<'a> InfoPropertyValue<'a> for &'a $ty {
             const TYPE: PropertyTypeMod = PropertyTypeMod::Plain($pty);
@@ -143,6 +197,13 @@ macro_rules! impl_value {
                     _ => None,
                 }
             }
+
+            fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+                match type_ {
+                    $ptypat => Some(($enc)(*self)),
+                    _ => None,
+                }
+            }
         }
 
         $(#[$attr])*
@@ -172,6 +233,17 @@ macro_rules! impl_value {
                     _ => None,
                 }
             }
+
+            fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+                None
+            }
+
+            fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+                match type_ {
+                    PropertyTypeMod::Array($ptypat) => Some(self.iter().flat_map(|v| ($enc)(v)).collect()),
+                    _ => None,
+                }
+            }
         }
 
         $(#[$attr])*
@@ -204,7 +276,7 @@ macro_rules! impl_value {
                         false => Cow::Owned({
                             let mut vec = Vec::new();
                             unsafe {
-                                let end = ty_data.add((data.len() / mem::size_of::<u16>()));
+                                let end = ty_data.add(data.len() / mem::size_of::<$ty>());
                                 while ty_data < end {
                                     vec.push(ptr::read_unaligned(ty_data));
                                     ty_data = ty_data.add(1);
@@ -216,6 +288,17 @@ macro_rules! impl_value {
                     _ => None,
                 }
             }
+
+            fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+                None
+            }
+
+            fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+                match type_ {
+                    PropertyTypeMod::Array($ptypat) => Some(self.iter().flat_map(|v| ($enc)(v)).collect()),
+                    _ => None,
+                }
+            }
         }
 
         $(#[$attr])*
@@ -233,9 +316,20 @@ macro_rules! impl_value {
             fn get(type_: PropertyTypeMod, data: &'a [u8]) -> Option<Self> {
                 Cow::<[$ty]>::get(type_, data).map(|v| v.into_owned())
             }
+
+            fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+                None
+            }
+
+            fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+                match type_ {
+                    PropertyTypeMod::Array($ptypat) => Some(self.iter().flat_map(|v| ($enc)(v)).collect()),
+                    _ => None,
+                }
+            }
         }
     };
-    (@cow $(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, $data:pat) => {
+    (@cow $(#[$attr:meta])* $ty:ty = $pty:path{$ptypat:pat}, $data:pat, $enc:expr) => {
         $(#[$attr])*
         impl<'a> InfoPropertyValue<'a> for Cow<'a, $ty> {
             const TYPE: PropertyTypeMod = PropertyTypeMod::Plain($pty);
@@ -254,43 +348,63 @@ macro_rules! impl_value {
                     _ => None,
                 }
             }
+
+            fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+                match type_ {
+                    $ptypat => Some(($enc)(self)),
+                    _ => None,
+                }
+            }
         }
     };
 }
 
+// Scalar numeric and boolean support: each of these primitives gets a `Plain` impl on the bare
+// type (`bool`, `u8`, `u16`, ...), plus `&'a [T]`/`Cow<'a, [T]>`/`Vec<T>` impls over
+// `PropertyTypeMod::Array` for reading several elements at once. Note that this is `Array`, not
+// `List` - per [`PropertyTypeMod::is_valid`], Windows only allows `DEVPROP_TYPEMOD_LIST` on
+// string-shaped base types, so a `Vec<u32>` (for example) has no `List`-typed counterpart.
 impl_value! {
     @primitives
         bool = PropertyType::Boolean, (&[b] => Some(match b {
             0 => false,
             _ => true,
-        })),
-        u8 = PropertyType::Byte, (&[b] => Some(b)),
-        i8 = PropertyType::SByte, (&[b] => Some(i8::from_ne_bytes([b]))),
-        u16 = PropertyType::UInt16, (&[b0, b1] => Some(u16::from_ne_bytes([b0, b1]))),
-        i16 = PropertyType::Int16, (&[b0, b1] => Some(i16::from_ne_bytes([b0, b1]))),
-        i32 = PropertyType::Int32, (&[b0, b1, b2, b3] => Some(i32::from_ne_bytes([b0, b1, b2, b3]))),
-        u32 = PropertyType::UInt32, (&[b0, b1, b2, b3] => Some(u32::from_ne_bytes([b0, b1, b2, b3]))),
-        f32 = PropertyType::Float, (&[b0, b1, b2, b3] => Some(f32::from_ne_bytes([b0, b1, b2, b3]))),
+        })), (|v: &bool| vec![*v as u8]),
+        u8 = PropertyType::Byte, (&[b] => Some(b)), (|v: &u8| vec![*v]),
+        i8 = PropertyType::SByte, (&[b] => Some(i8::from_ne_bytes([b]))), (|v: &i8| v.to_ne_bytes().to_vec()),
+        u16 = PropertyType::UInt16, (&[b0, b1] => Some(u16::from_ne_bytes([b0, b1]))), (|v: &u16| v.to_ne_bytes().to_vec()),
+        i16 = PropertyType::Int16, (&[b0, b1] => Some(i16::from_ne_bytes([b0, b1]))), (|v: &i16| v.to_ne_bytes().to_vec()),
+        i32 = PropertyType::Int32, (&[b0, b1, b2, b3] => Some(i32::from_ne_bytes([b0, b1, b2, b3]))), (|v: &i32| v.to_ne_bytes().to_vec()),
+        u32 = PropertyType::UInt32, (&[b0, b1, b2, b3] => Some(u32::from_ne_bytes([b0, b1, b2, b3]))), (|v: &u32| v.to_ne_bytes().to_vec()),
+        f32 = PropertyType::Float, (&[b0, b1, b2, b3] => Some(f32::from_ne_bytes([b0, b1, b2, b3]))), (|v: &f32| v.to_ne_bytes().to_vec()),
 }
 impl_value! {
     @primitives
-        u64 = PropertyType::UInt64{PropertyType::UInt64 | PropertyType::FileTime}, (&[b0, b1, b2, b3, b4, b5, b6, b7] => Some(u64::from_ne_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))),
-        i64 = PropertyType::Int64{PropertyType::Int64 | PropertyType::Decimal | PropertyType::Currency}, (&[b0, b1, b2, b3, b4, b5, b6, b7] => Some(i64::from_ne_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))),
-        f64 = PropertyType::Double{PropertyType::Double | PropertyType::Date}, (&[b0, b1, b2, b3, b4, b5, b6, b7] => Some(f64::from_ne_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))),
+        u64 = PropertyType::UInt64{PropertyType::UInt64 | PropertyType::FileTime}, (&[b0, b1, b2, b3, b4, b5, b6, b7] => Some(u64::from_ne_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))), (|v: &u64| v.to_ne_bytes().to_vec()),
+        i64 = PropertyType::Int64{PropertyType::Int64 | PropertyType::Currency}, (&[b0, b1, b2, b3, b4, b5, b6, b7] => Some(i64::from_ne_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))), (|v: &i64| v.to_ne_bytes().to_vec()),
+        f64 = PropertyType::Double{PropertyType::Double | PropertyType::Date}, (&[b0, b1, b2, b3, b4, b5, b6, b7] => Some(f64::from_ne_bytes([b0, b1, b2, b3, b4, b5, b6, b7]))), (|v: &f64| v.to_ne_bytes().to_vec()),
 }
 #[cfg(target_endian = "little")]
 impl_value! {
     @primitives
         PropertyType = PropertyType::PropertyType, (&[b @ PropertyType::MIN_DEVPROP_TYPE_U8..=PropertyType::MAX_DEVPROP_TYPE_U8, 0, 0, 0] =>
             PropertyType::from_win32(b as u32)
-        ),
+        ), (|v: &PropertyType| {
+            let mut bytes = [0u8; 4];
+            bytes[0] = v.win32_devprop_type() as u8;
+            bytes.to_vec()
+        }),
 }
 #[cfg(target_endian = "big")]
 impl_value! {
     @primitives
         PropertyType = PropertyType::PropertyType, (&[0, 0, 0, b @ PropertyType::MIN_DEVPROP_TYPE_U8..=PropertyType::MAX_DEVPROP_TYPE_U8] =>
             PropertyType::from_win32(b as u32)
-        ),
+        ), (|v: &PropertyType| {
+            let mut bytes = [0u8; 4];
+            bytes[3] = v.win32_devprop_type() as u8;
+            bytes.to_vec()
+        }),
 }
 
 impl_value! {
@@ -330,7 +444,17 @@ fn variant_to_systemtime(v: f64) -> Option<SYSTEMTIME> {
     }
 }
 
-fn filetime_to_systemtime(v: &FILETIME) -> Option<SYSTEMTIME> {
+#[cfg(feature = "win32-extras")]
+pub(super) fn systemtime_to_variant(v: &SYSTEMTIME) -> Option<f64> {
+    use windows::Win32::{Foundation::BOOL, System::Ole::SystemTimeToVariantTime};
+    let mut out = 0f64;
+    match BOOL(unsafe { SystemTimeToVariantTime(v, &mut out) }).as_bool() {
+        true => Some(out),
+        false => None,
+    }
+}
+
+pub(super) fn filetime_to_systemtime(v: &FILETIME) -> Option<SYSTEMTIME> {
     let mut out = SYSTEMTIME::default();
     match unsafe { FileTimeToSystemTime(v, &mut out) }.as_bool() {
         true => Some(out),
@@ -352,6 +476,15 @@ fn filetime_to_std(v: &FILETIME) -> Option<SystemTime> {
     filetime_epoch().and_then(|jan1601| jan1601.checked_add(delta))
 }
 
+pub(super) fn std_to_filetime(v: &SystemTime) -> Option<FILETIME> {
+    let delta = v.duration_since(filetime_epoch()?).ok()?;
+    let _100ns = delta.as_secs() * 10_000_000 + (delta.subsec_nanos() / 100) as u64;
+    Some(FILETIME {
+        dwLowDateTime: _100ns as u32,
+        dwHighDateTime: (_100ns >> 32) as u32,
+    })
+}
+
 impl<'a> InfoPropertyValue<'a> for SYSTEMTIME {
     const TYPE: PropertyTypeMod = PropertyTypeMod::Plain(PropertyType::Date);
 
@@ -371,6 +504,15 @@ impl<'a> InfoPropertyValue<'a> for SYSTEMTIME {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            #[cfg(feature = "win32-extras")]
+            PropertyType::Date => systemtime_to_variant(self).map(|v| v.to_ne_bytes().to_vec()),
+            PropertyType::FileTime => systemtime_to_filetime(self).map(|ft| pod_to_bytes(&ft)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for FILETIME {
@@ -391,6 +533,14 @@ impl<'a> InfoPropertyValue<'a> for FILETIME {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::FileTime => Some(pod_to_bytes(self)),
+            PropertyType::Date => filetime_to_systemtime(self).and_then(|st| st.to_bytes_plain(PropertyType::Date)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Cow<'a, FILETIME> {
@@ -410,9 +560,13 @@ impl<'a> InfoPropertyValue<'a> for Cow<'a, FILETIME> {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        FILETIME::to_bytes_plain(self, type_)
+    }
 }
 
-impl_value! { @ref FILETIME = PropertyType::FileTime{PropertyType::FileTime}, _ }
+impl_value! { @ref FILETIME = PropertyType::FileTime{PropertyType::FileTime}, _, |v: &FILETIME| pod_to_bytes(v) }
 
 impl<'a> InfoPropertyValue<'a> for SystemTime {
     const TYPE: PropertyTypeMod = PropertyTypeMod::Plain(PropertyType::FileTime);
@@ -428,6 +582,90 @@ impl<'a> InfoPropertyValue<'a> for SystemTime {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::FileTime | PropertyType::Date => std_to_filetime(self).and_then(|ft| ft.to_bytes_plain(type_)),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> InfoPropertyValue<'a> for Vec<SystemTime> {
+    const TYPE: PropertyTypeMod = PropertyTypeMod::Array(PropertyType::FileTime);
+
+    fn supports_type(type_: PropertyTypeMod) -> bool {
+        match type_ {
+            PropertyTypeMod::Array(PropertyType::Date | PropertyType::FileTime) => true,
+            _ => false,
+        }
+    }
+
+    fn get_plain(_: PropertyType, _: &'a [u8]) -> Option<Self> {
+        None
+    }
+
+    fn get(type_: PropertyTypeMod, data: &'a [u8]) -> Option<Self> {
+        match type_ {
+            PropertyTypeMod::Array(ty @ (PropertyType::Date | PropertyType::FileTime)) =>
+                match data.len() % mem::size_of::<FILETIME>() {
+                    0 => data
+                        .chunks_exact(mem::size_of::<FILETIME>())
+                        .map(|chunk| SystemTime::get_plain(ty, chunk))
+                        .collect(),
+                    _ => None,
+                },
+            _ => None,
+        }
+    }
+
+    fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyTypeMod::Array(ty @ (PropertyType::Date | PropertyType::FileTime)) =>
+                self.iter().map(|v| v.to_bytes_plain(ty)).collect::<Option<Vec<_>>>().map(|v| v.concat()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "chrono")))]
+impl<'a> InfoPropertyValue<'a> for chrono::DateTime<chrono::Utc> {
+    const TYPE: PropertyTypeMod = PropertyTypeMod::Plain(PropertyType::FileTime);
+
+    fn supports_type(type_: PropertyTypeMod) -> bool {
+        SystemTime::supports_type(type_)
+    }
+
+    fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
+        SystemTime::get_plain(type_, data).map(Self::from)
+    }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        SystemTime::from(*self).to_bytes_plain(type_)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "time")))]
+impl<'a> InfoPropertyValue<'a> for time::OffsetDateTime {
+    const TYPE: PropertyTypeMod = PropertyTypeMod::Plain(PropertyType::FileTime);
+
+    fn supports_type(type_: PropertyTypeMod) -> bool {
+        SystemTime::supports_type(type_)
+    }
+
+    fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
+        SystemTime::get_plain(type_, data).and_then(|st| Self::try_from(st).ok())
+    }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        SystemTime::try_from(*self).ok()?.to_bytes_plain(type_)
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for HRESULT {
@@ -447,6 +685,11 @@ impl<'a> InfoPropertyValue<'a> for HRESULT {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+        // an HRESULT doesn't carry enough information to recover the original WIN32_ERROR/NTSTATUS
+        None
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Error {
@@ -459,6 +702,10 @@ impl<'a> InfoPropertyValue<'a> for Error {
     fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
         HRESULT::get_plain(type_, data).map(Into::into)
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        self.code().to_bytes_plain(type_)
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for io::Error {
@@ -471,6 +718,11 @@ impl<'a> InfoPropertyValue<'a> for io::Error {
     fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
         Error::get_plain(type_, data).map(Into::into)
     }
+
+    fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+        // an io::Error doesn't carry enough information to recover the original WIN32_ERROR/NTSTATUS
+        None
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for PropertyTypeMod {
@@ -483,6 +735,13 @@ impl<'a> InfoPropertyValue<'a> for PropertyTypeMod {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::PropertyType => Some(self.win32_devprop_type().to_ne_bytes().to_vec()),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for () {
@@ -494,6 +753,13 @@ impl<'a> InfoPropertyValue<'a> for () {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::Null => Some(Vec::new()),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for &'a () {
@@ -505,6 +771,10 @@ impl<'a> InfoPropertyValue<'a> for &'a () {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        <() as InfoPropertyValue>::to_bytes_plain(self, type_)
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Cow<'a, ()> {
@@ -516,6 +786,10 @@ impl<'a> InfoPropertyValue<'a> for Cow<'a, ()> {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        <() as InfoPropertyValue>::to_bytes_plain(self, type_)
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for WideCString {
@@ -528,6 +802,14 @@ impl<'a> InfoPropertyValue<'a> for WideCString {
     fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
         <Cow<WideCStr> as InfoPropertyValue>::get_plain(type_, data).map(|s| s.into_owned())
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                Some(widecstr_to_bytes(self)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for &'a WideCStr {
@@ -540,6 +822,14 @@ impl<'a> InfoPropertyValue<'a> for &'a WideCStr {
     fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
         <&WideStr as InfoPropertyValue>::get_plain(type_, data).and_then(|s| WideCStr::from_slice(s.as_slice()).ok())
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                Some(widecstr_to_bytes(self)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Cow<'a, WideCStr> {
@@ -555,6 +845,14 @@ impl<'a> InfoPropertyValue<'a> for Cow<'a, WideCStr> {
             Cow::Owned(s) => WideCString::from_vec(s.into_vec()).ok().map(Cow::Owned),
         })
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                Some(widecstr_to_bytes(self)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for &'a WideStr {
@@ -575,6 +873,14 @@ impl<'a> InfoPropertyValue<'a> for &'a WideStr {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                WideCString::from_vec(self.as_slice().to_vec()).ok().map(|s| widecstr_to_bytes(&s)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Cow<'a, WideStr> {
@@ -596,6 +902,14 @@ impl<'a> InfoPropertyValue<'a> for Cow<'a, WideStr> {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                WideCString::from_vec(self.as_slice().to_vec()).ok().map(|s| widecstr_to_bytes(&s)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for WideString {
@@ -608,6 +922,14 @@ impl<'a> InfoPropertyValue<'a> for WideString {
     fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
         <Cow<WideStr> as InfoPropertyValue>::get_plain(type_, data).map(|s| s.into_owned())
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                WideCString::from_vec(self.as_slice().to_vec()).ok().map(|s| widecstr_to_bytes(&s)),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Vec<Cow<'a, WideCStr>> {
@@ -631,6 +953,21 @@ impl<'a> InfoPropertyValue<'a> for Vec<Cow<'a, WideCStr>> {
             _ => None,
         }
     }
+
+    fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyTypeMod::List(ty) if ty.is_string() => {
+                let mut data = self.iter().flat_map(|s| widecstr_to_bytes(s)).collect::<Vec<_>>();
+                data.extend(0u16.to_ne_bytes());
+                Some(data)
+            },
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for Vec<WideCString> {
@@ -648,6 +985,21 @@ impl<'a> InfoPropertyValue<'a> for Vec<WideCString> {
         <Vec<Cow<WideCStr>> as InfoPropertyValue>::get(type_, data)
             .map(|strings| strings.into_iter().map(|s| s.into_owned()).collect())
     }
+
+    fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+        None
+    }
+
+    fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyTypeMod::List(ty) if ty.is_string() => {
+                let mut data = self.iter().flat_map(|s| widecstr_to_bytes(s)).collect::<Vec<_>>();
+                data.extend(0u16.to_ne_bytes());
+                Some(data)
+            },
+            _ => None,
+        }
+    }
 }
 
 impl<'a> InfoPropertyValue<'a> for String {
@@ -660,17 +1012,64 @@ impl<'a> InfoPropertyValue<'a> for String {
     fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
         <Cow<WideCStr> as InfoPropertyValue>::get_plain(type_, data).map(|s| s.to_string_lossy())
     }
+
+    fn to_bytes_plain(&self, type_: PropertyType) -> Option<Vec<u8>> {
+        match type_ {
+            PropertyType::String | PropertyType::StringIndirect | PropertyType::SecurityDescriptorString =>
+                wide_bytes(self),
+            _ => None,
+        }
+    }
 }
 
-impl<'a> InfoPropertyValue<'a> for Vec<String> {
-    const TYPE: PropertyTypeMod = PropertyTypeMod::List(PropertyType::String);
+/// Implements [`InfoPropertyValue`] for a [`FromIterator<T>`](FromIterator)-able collection, for
+/// any element type `T` that already has its own `Vec<T>: InfoPropertyValue` impl (every scalar
+/// this module supports - `String`, the `impl_value!` primitives, ...).
+///
+/// This generalizes the hand-written `Vec<String>` impl this crate started with to any such
+/// collection (`BTreeSet`, `HashSet`, `Box<[T]>`, a `smallvec::SmallVec` if one's ever added as a
+/// dependency, ...) over any element, not just `String`, without duplicating the decode/encode
+/// logic per target type - it's entirely proxied through `Vec<T>`'s own impl, so a `List` or
+/// `Array` property's type-matching lives in exactly one place per element type.
+///
+/// `Vec<T>` itself is intentionally excluded here: it already has a direct impl (hand-written for
+/// `String`-shaped types, generated by `impl_value!` for numeric primitives), and a blanket
+/// `FromIterator` impl covering it too would conflict.
+macro_rules! impl_property_list {
+    ($($coll:ty => $elem:ty,)*) => {
+        $(
+            impl<'a> InfoPropertyValue<'a> for $coll {
+                const TYPE: PropertyTypeMod = <Vec<$elem> as InfoPropertyValue<'a>>::TYPE;
 
-    fn supports_type(type_: PropertyTypeMod) -> bool {
-        Vec::<Cow<WideCStr>>::supports_type(type_)
-    }
+                fn supports_type(type_: PropertyTypeMod) -> bool {
+                    Vec::<$elem>::supports_type(type_)
+                }
 
-    fn get_plain(type_: PropertyType, data: &'a [u8]) -> Option<Self> {
-        <Vec<Cow<WideCStr>> as InfoPropertyValue>::get_plain(type_, data)
-            .map(|strings| strings.into_iter().map(|s| s.to_string_lossy()).collect())
-    }
+                fn get_plain(_: PropertyType, _: &'a [u8]) -> Option<Self> {
+                    None
+                }
+
+                fn get(type_: PropertyTypeMod, data: &'a [u8]) -> Option<Self> {
+                    Vec::<$elem>::get(type_, data).map(|elements| elements.into_iter().collect())
+                }
+
+                fn to_bytes_plain(&self, _: PropertyType) -> Option<Vec<u8>> {
+                    None
+                }
+
+                fn to_bytes(&self, type_: PropertyTypeMod) -> Option<Vec<u8>> {
+                    self.iter().cloned().collect::<Vec<$elem>>().to_bytes(type_)
+                }
+            }
+        )*
+    };
+}
+
+impl_property_list! {
+    Vec<String> => String,
+    std::collections::BTreeSet<String> => String,
+    std::collections::HashSet<String> => String,
+    Box<[String]> => String,
+    std::collections::BTreeSet<u32> => u32,
+    Box<[u32]> => u32,
 }
@@ -0,0 +1,592 @@
+//! Property-filtered device enumeration via the [DevQuery API][devquery]
+//!
+//! Unlike walking an [`InfoSet`](super::InfoSet) and reading each property individually, this
+//! builds a [`DEVPROP_FILTER_EXPRESSION`] list and a requested-property list up front and hands
+//! both to [`DevGetObjects`][devgetobjects], which performs the filtering and property fetch in a
+//! single round trip.
+//!
+//! [devquery]: https://learn.microsoft.com/en-us/windows/win32/devquery/device-query
+//! [devgetobjects]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/nf-devquery-devgetobjects
+
+use {
+    super::{Property, PropertyKey, PropertyTypeMod},
+    std::{
+        ffi::c_void,
+        fmt::{self, Debug, Formatter},
+        slice,
+    },
+    widestring::{WideCStr, WideCString},
+    windows::{
+        core::{Result as WinResult, PWSTR},
+        Win32::Devices::DeviceAndDriverInstallation::{
+            DevCloseObjectQuery, DevCreateObjectQuery, DevFreeObjects, DevGetObjects,
+            DevObjectTypeDevice, DevObjectTypeDeviceInterface, DevQueryResultAdd, DevQueryResultRemove,
+            DevQueryResultStateChange,
+            DevQueryResultUpdate, DevQueryStateAborted, DevQueryStateClosed, DevQueryStateEnumCompleted,
+            DevQueryStateInitialized, DEVPROPCOMPKEY, DEVPROPERTY, DEVPROP_FILTER_EXPRESSION, DEVPROP_OPERATOR,
+            DEVPROP_OPERATOR_AND_CLOSE, DEVPROP_OPERATOR_AND_OPEN, DEVPROP_OPERATOR_ARRAY_CONTAINS,
+            DEVPROP_OPERATOR_BEGINS_WITH, DEVPROP_OPERATOR_BITWISE_AND, DEVPROP_OPERATOR_BITWISE_OR,
+            DEVPROP_OPERATOR_CONTAINS, DEVPROP_OPERATOR_ENDS_WITH, DEVPROP_OPERATOR_EQUALS,
+            DEVPROP_OPERATOR_EQUALS_IGNORE_CASE, DEVPROP_OPERATOR_GREATER_THAN, DEVPROP_OPERATOR_GREATER_THAN_EQUALS,
+            DEVPROP_OPERATOR_LESS_THAN, DEVPROP_OPERATOR_LESS_THAN_EQUALS, DEVPROP_OPERATOR_LIST_CONTAINS,
+            DEVPROP_OPERATOR_NOT_CLOSE, DEVPROP_OPERATOR_NOT_EQUALS, DEVPROP_OPERATOR_NOT_EQUALS_IGNORE_CASE,
+            DEVPROP_OPERATOR_NOT_OPEN, DEVPROP_OPERATOR_OR_CLOSE, DEVPROP_OPERATOR_OR_OPEN, DEVPROP_STORE_SYSTEM,
+            DEV_OBJECT, DEV_OBJECT_TYPE, DEV_QUERY_FLAGS, DEV_QUERY_RESULT_ACTION_DATA, DEV_QUERY_STATE, HDEVQUERY,
+        },
+    },
+};
+
+/// An ordered comparison for [`PropertyFilter::ordered`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FilterOrdering {
+    /// Greater than
+    #[doc(alias = "DEVPROP_OPERATOR_GREATER_THAN")]
+    Greater,
+    /// Greater than or equal to
+    #[doc(alias = "DEVPROP_OPERATOR_GREATER_THAN_EQUALS")]
+    GreaterEquals,
+    /// Less than
+    #[doc(alias = "DEVPROP_OPERATOR_LESS_THAN")]
+    Less,
+    /// Less than or equal to
+    #[doc(alias = "DEVPROP_OPERATOR_LESS_THAN_EQUALS")]
+    LessEquals,
+}
+
+/// A substring match for [`PropertyFilter::substring`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FilterSubstring {
+    /// The property value contains the filter value anywhere within it
+    #[doc(alias = "DEVPROP_OPERATOR_CONTAINS")]
+    Contains,
+    /// The property value begins with the filter value
+    #[doc(alias = "DEVPROP_OPERATOR_BEGINS_WITH")]
+    BeginsWith,
+    /// The property value ends with the filter value
+    #[doc(alias = "DEVPROP_OPERATOR_ENDS_WITH")]
+    EndsWith,
+}
+
+/// A single property comparison used to filter a [`query`](DevObjectQuery)
+///
+/// Wraps a [`DEVPROP_FILTER_EXPRESSION`][wraps].
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/ns-devquery-devprop_filter_expression
+pub struct PropertyFilter<'a> {
+    key: PropertyKey,
+    operator: DEVPROP_OPERATOR,
+    value: Property<'a>,
+}
+
+impl<'a> PropertyFilter<'a> {
+    /// Build a filter predicate from a property key, comparison operator, and expected value
+    pub fn new(key: PropertyKey, operator: DEVPROP_OPERATOR, value: Property<'a>) -> Self {
+        Self { key, operator, value }
+    }
+
+    /// A filter matching devices whose `key` property equals `value`
+    #[doc(alias = "DEVPROP_OPERATOR_EQUALS")]
+    pub fn equals(key: PropertyKey, value: Property<'a>) -> Self {
+        Self::new(key, DEVPROP_OPERATOR_EQUALS, value)
+    }
+
+    /// A filter matching devices whose `key` property does not equal `value`
+    #[doc(alias = "DEVPROP_OPERATOR_NOT_EQUALS")]
+    pub fn not_equals(key: PropertyKey, value: Property<'a>) -> Self {
+        Self::new(key, DEVPROP_OPERATOR_NOT_EQUALS, value)
+    }
+
+    /// An [`equals`](Self::equals)/[`not_equals`](Self::not_equals) filter that ignores case
+    /// when comparing [string](super::PropertyType::is_string) values
+    #[doc(alias = "DEVPROP_OPERATOR_EQUALS_IGNORE_CASE")]
+    #[doc(alias = "DEVPROP_OPERATOR_NOT_EQUALS_IGNORE_CASE")]
+    pub fn equals_ignore_case(key: PropertyKey, value: Property<'a>, negate: bool) -> Option<Self> {
+        if !value.type_.base_type().is_string() {
+            return None
+        }
+        let operator = match negate {
+            false => DEVPROP_OPERATOR_EQUALS_IGNORE_CASE,
+            true => DEVPROP_OPERATOR_NOT_EQUALS_IGNORE_CASE,
+        };
+        Some(Self::new(key, operator, value))
+    }
+
+    /// A filter matching devices whose `key` property orders against `value` as requested
+    ///
+    /// Returns `None` unless `value`'s type
+    /// [is numeric](super::PropertyType::is_int)/[is a float](super::PropertyType::is_float).
+    pub fn ordered(key: PropertyKey, ordering: FilterOrdering, value: Property<'a>) -> Option<Self> {
+        let base = value.type_.base_type();
+        if !(base.is_int() || base.is_float()) {
+            return None
+        }
+        let operator = match ordering {
+            FilterOrdering::Greater => DEVPROP_OPERATOR_GREATER_THAN,
+            FilterOrdering::GreaterEquals => DEVPROP_OPERATOR_GREATER_THAN_EQUALS,
+            FilterOrdering::Less => DEVPROP_OPERATOR_LESS_THAN,
+            FilterOrdering::LessEquals => DEVPROP_OPERATOR_LESS_THAN_EQUALS,
+        };
+        Some(Self::new(key, operator, value))
+    }
+
+    /// A filter performing a bitwise `AND`/`OR` of `value` against `key`'s property, matching if
+    /// the result is non-zero
+    ///
+    /// Returns `None` unless `value`'s type [is an integer](super::PropertyType::is_int).
+    #[doc(alias = "DEVPROP_OPERATOR_BITWISE_AND")]
+    #[doc(alias = "DEVPROP_OPERATOR_BITWISE_OR")]
+    pub fn bitwise(key: PropertyKey, or: bool, value: Property<'a>) -> Option<Self> {
+        if !value.type_.base_type().is_int() {
+            return None
+        }
+        let operator = match or {
+            false => DEVPROP_OPERATOR_BITWISE_AND,
+            true => DEVPROP_OPERATOR_BITWISE_OR,
+        };
+        Some(Self::new(key, operator, value))
+    }
+
+    /// A filter matching [string](super::PropertyType::is_string)-typed properties containing,
+    /// beginning with, or ending with `value`
+    ///
+    /// Returns `None` unless `value`'s type [is a string](super::PropertyType::is_string).
+    pub fn substring(key: PropertyKey, position: FilterSubstring, value: Property<'a>) -> Option<Self> {
+        if !value.type_.base_type().is_string() {
+            return None
+        }
+        let operator = match position {
+            FilterSubstring::Contains => DEVPROP_OPERATOR_CONTAINS,
+            FilterSubstring::BeginsWith => DEVPROP_OPERATOR_BEGINS_WITH,
+            FilterSubstring::EndsWith => DEVPROP_OPERATOR_ENDS_WITH,
+        };
+        Some(Self::new(key, operator, value))
+    }
+
+    /// A filter matching an [array](PropertyTypeMod::Array)/[list](PropertyTypeMod::List)-typed
+    /// property that contains `value` among its elements
+    ///
+    /// Returns `None` unless `key`'s own type is a
+    /// [sequence](PropertyTypeMod::is_sequence) of `value`'s type.
+    #[doc(alias = "DEVPROP_OPERATOR_ARRAY_CONTAINS")]
+    #[doc(alias = "DEVPROP_OPERATOR_LIST_CONTAINS")]
+    pub fn sequence_contains(key: PropertyKey, key_type: PropertyTypeMod, value: Property<'a>) -> Option<Self> {
+        let operator = match key_type {
+            PropertyTypeMod::Array(ty) if ty == value.type_.base_type() => DEVPROP_OPERATOR_ARRAY_CONTAINS,
+            PropertyTypeMod::List(ty) if ty == value.type_.base_type() => DEVPROP_OPERATOR_LIST_CONTAINS,
+            _ => return None,
+        };
+        Some(Self::new(key, operator, value))
+    }
+
+    /// This filter's comparison operator
+    pub const fn operator(&self) -> DEVPROP_OPERATOR {
+        self.operator
+    }
+
+    /// The property key being compared
+    pub const fn key(&self) -> &PropertyKey {
+        &self.key
+    }
+
+    /// The value being compared against
+    pub const fn value(&self) -> &Property<'a> {
+        &self.value
+    }
+
+    fn win32_expression(&self) -> DEVPROP_FILTER_EXPRESSION {
+        DEVPROP_FILTER_EXPRESSION {
+            Operator: self.operator,
+            Property: DEVPROPERTY {
+                CompKey: DEVPROPCOMPKEY {
+                    Key: self.key.into_win32(),
+                    Store: DEVPROP_STORE_SYSTEM,
+                    LocaleName: PWSTR::null(),
+                },
+                Type: self.value.type_.win32_devprop_type(),
+                BufferSize: self.value.data().len() as u32,
+                Buffer: self.value.data().as_ptr() as *mut _,
+            },
+        }
+    }
+}
+
+/// A tree of [`PropertyFilter`] predicates, combined with logical `AND`/`OR`/`NOT` grouping
+///
+/// This flattens to the parenthesized flat array of [`DEVPROP_FILTER_EXPRESSION`]s that
+/// [`DevGetObjects`][devgetobjects]/[`DevCreateObjectQuery`][devcreateobjectquery] expect, using
+/// the `DEVPROP_OPERATOR_*_OPEN`/`_CLOSE` grouping operators as parentheses.
+///
+/// [devgetobjects]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/nf-devquery-devgetobjects
+/// [devcreateobjectquery]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/nf-devquery-devcreateobjectquery
+#[non_exhaustive]
+pub enum FilterExpr<'a> {
+    /// A single property comparison
+    Leaf(PropertyFilter<'a>),
+    /// All of the contained expressions must match
+    #[doc(alias = "DEVPROP_OPERATOR_AND_OPEN")]
+    #[doc(alias = "DEVPROP_OPERATOR_AND_CLOSE")]
+    And(Vec<FilterExpr<'a>>),
+    /// Any of the contained expressions must match
+    #[doc(alias = "DEVPROP_OPERATOR_OR_OPEN")]
+    #[doc(alias = "DEVPROP_OPERATOR_OR_CLOSE")]
+    Or(Vec<FilterExpr<'a>>),
+    /// The contained expression must not match
+    #[doc(alias = "DEVPROP_OPERATOR_NOT_OPEN")]
+    #[doc(alias = "DEVPROP_OPERATOR_NOT_CLOSE")]
+    Not(Box<FilterExpr<'a>>),
+}
+
+impl<'a> FilterExpr<'a> {
+    /// Flatten this expression tree to the parenthesized list of
+    /// [`DEVPROP_FILTER_EXPRESSION`]s the Win32 DevQuery calls expect
+    fn flatten_into(&self, out: &mut Vec<DEVPROP_FILTER_EXPRESSION>) {
+        fn group(
+            out: &mut Vec<DEVPROP_FILTER_EXPRESSION>,
+            open: DEVPROP_OPERATOR,
+            close: DEVPROP_OPERATOR,
+            exprs: &[FilterExpr<'_>],
+        ) {
+            out.push(DEVPROP_FILTER_EXPRESSION {
+                Operator: open,
+                Property: DEVPROPERTY::default(),
+            });
+            for expr in exprs {
+                expr.flatten_into(out);
+            }
+            out.push(DEVPROP_FILTER_EXPRESSION {
+                Operator: close,
+                Property: DEVPROPERTY::default(),
+            });
+        }
+
+        match self {
+            Self::Leaf(filter) => out.push(filter.win32_expression()),
+            Self::And(exprs) => group(out, DEVPROP_OPERATOR_AND_OPEN, DEVPROP_OPERATOR_AND_CLOSE, exprs),
+            Self::Or(exprs) => group(out, DEVPROP_OPERATOR_OR_OPEN, DEVPROP_OPERATOR_OR_CLOSE, exprs),
+            Self::Not(expr) => group(out, DEVPROP_OPERATOR_NOT_OPEN, DEVPROP_OPERATOR_NOT_CLOSE, std::slice::from_ref(expr)),
+        }
+    }
+
+    fn win32_expressions(&self) -> Vec<DEVPROP_FILTER_EXPRESSION> {
+        let mut out = Vec::new();
+        self.flatten_into(&mut out);
+        out
+    }
+}
+
+impl<'a> From<PropertyFilter<'a>> for FilterExpr<'a> {
+    fn from(filter: PropertyFilter<'a>) -> Self {
+        Self::Leaf(filter)
+    }
+}
+
+/// Query for device interfaces or device nodes (see [`object_type`](Self::object_type)), filtered
+/// by [`PropertyFilter`] predicates and pre-fetching a set of requested [`PropertyKey`]s, e.g. all
+/// [`DEVCLASS_MONITOR`](super::DEVCLASS_MONITOR) nodes whose
+/// [`DEVICE_PARENT`](PropertyKey::DEVICE_PARENT) begins with a given adapter's instance path
+///
+/// This is a builder around [`DevGetObjects`][wraps], which performs the filtering and property
+/// fetch entirely in the OS rather than enumerating every device and checking each one.
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/nf-devquery-devgetobjects
+pub struct DevObjectQuery<'a> {
+    object_type: DEV_OBJECT_TYPE,
+    properties: Vec<PropertyKey>,
+    filters: Vec<FilterExpr<'a>>,
+}
+
+impl<'a> DevObjectQuery<'a> {
+    /// Start building a query over device interface objects
+    pub fn new() -> Self {
+        Self {
+            object_type: DevObjectTypeDeviceInterface,
+            properties: Vec::new(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Start building a query over device node objects, e.g. all present
+    /// [`DEVCLASS_MONITOR`](super::DEVCLASS_MONITOR) devices, rather than their interfaces
+    pub fn devices() -> Self {
+        Self {
+            object_type: DevObjectTypeDevice,
+            ..Self::new()
+        }
+    }
+
+    /// Override the kind of object ([`DEV_OBJECT_TYPE`]) this query enumerates
+    ///
+    /// [`new`](Self::new) and [`devices`](Self::devices) already cover the common cases; use this
+    /// to pass a `DEV_OBJECT_TYPE` they don't have a constructor for.
+    pub fn object_type(mut self, object_type: DEV_OBJECT_TYPE) -> Self {
+        self.object_type = object_type;
+        self
+    }
+
+    /// Request that the given property be pre-fetched for each matching object
+    pub fn property(mut self, key: PropertyKey) -> Self {
+        self.properties.push(key);
+        self
+    }
+
+    /// Add a filter predicate (or [nested expression tree](FilterExpr)) that a matching object's
+    /// properties must satisfy
+    pub fn filter(mut self, filter: impl Into<FilterExpr<'a>>) -> Self {
+        self.filters.push(filter.into());
+        self
+    }
+
+    /// Execute the query, returning every matching [`DevObject`]
+    #[doc(alias = "DevGetObjects")]
+    pub fn get(&self) -> WinResult<Vec<DevObject>> {
+        let properties: Vec<_> = self
+            .properties
+            .iter()
+            .map(|key| DEVPROPCOMPKEY {
+                Key: key.into_win32(),
+                Store: DEVPROP_STORE_SYSTEM,
+                LocaleName: PWSTR::null(),
+            })
+            .collect();
+        let filters: Vec<_> = self.filters.iter().flat_map(FilterExpr::win32_expressions).collect();
+
+        let mut count = 0u32;
+        let mut objects: *mut DEV_OBJECT = std::ptr::null_mut();
+        unsafe {
+            DevGetObjects(
+                self.object_type,
+                DEV_QUERY_FLAGS(0),
+                &properties,
+                &filters,
+                &mut count,
+                &mut objects,
+            )
+        }
+        .ok()?;
+
+        let slice = unsafe { slice::from_raw_parts(objects, count as usize) };
+        let result = slice.iter().map(|&object| unsafe { DevObject::from_win32(object) }).collect();
+        unsafe { DevFreeObjects(objects, count) };
+        Ok(result)
+    }
+}
+
+impl<'a> Default for DevObjectQuery<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The lifecycle state of a [`DeviceQuery`], delivered via [`DevQueryEvent::StateChanged`]
+///
+/// Mirrors [`DEV_QUERY_STATE`][wraps].
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/ne-devquery-dev_query_state
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[doc(alias = "DEV_QUERY_STATE")]
+#[non_exhaustive]
+pub enum DevQueryState {
+    /// The query has been created, but has not yet reported its initial results
+    Initialized,
+    /// The query has finished reporting every object present when it started
+    EnumCompleted,
+    /// The query was aborted, e.g. because the underlying device class was removed
+    Aborted,
+    /// The query has been closed and will report no further events
+    Closed,
+}
+
+impl DevQueryState {
+    fn from_win32(state: DEV_QUERY_STATE) -> Option<Self> {
+        Some(match state {
+            DevQueryStateInitialized => Self::Initialized,
+            DevQueryStateEnumCompleted => Self::EnumCompleted,
+            DevQueryStateAborted => Self::Aborted,
+            DevQueryStateClosed => Self::Closed,
+            _ => return None,
+        })
+    }
+}
+
+/// An event delivered to a [`DeviceQuery`] callback
+///
+/// Mirrors a [`DEV_QUERY_RESULT_ACTION_DATA`][wraps].
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/ns-devquery-dev_query_result_action_data
+#[non_exhaustive]
+pub enum DevQueryEvent {
+    /// A device matching the query was found, either during the initial enumeration or
+    /// afterwards
+    Added(DevObject),
+    /// A previously reported device's pre-fetched properties changed
+    Updated(DevObject),
+    /// A previously reported device no longer matches the query
+    Removed(DevObject),
+    /// The query's lifecycle state changed
+    StateChanged(DevQueryState),
+}
+
+impl DevQueryEvent {
+    unsafe fn from_win32(data: &DEV_QUERY_RESULT_ACTION_DATA) -> Option<Self> {
+        Some(match data.Action {
+            DevQueryResultAdd => Self::Added(DevObject::from_win32(data.u.DeviceObject)),
+            DevQueryResultUpdate => Self::Updated(DevObject::from_win32(data.u.DeviceObject)),
+            DevQueryResultRemove => Self::Removed(DevObject::from_win32(data.u.DeviceObject)),
+            DevQueryResultStateChange => Self::StateChanged(DevQueryState::from_win32(data.u.State)?),
+            _ => return None,
+        })
+    }
+}
+
+/// A live query for device interfaces, filtered by a [`FilterExpr`] and pre-fetching a set of
+/// requested [`PropertyKey`]s
+///
+/// Unlike [`DevObjectQuery`], which performs a single [`DevGetObjects`] round trip, this is a
+/// wrapper around [`DevCreateObjectQuery`][wraps], which asynchronously reports matching devices
+/// (and their later changes) to a callback until the [`DeviceQuery`] is dropped.
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/nf-devquery-devcreateobjectquery
+#[doc(alias = "DevCreateObjectQuery")]
+pub struct DeviceQuery {
+    handle: HDEVQUERY,
+    callback: *mut Box<dyn FnMut(DevQueryEvent) + Send>,
+}
+
+impl DeviceQuery {
+    /// Start a live query over device interface objects, delivering events to `callback` until
+    /// this is dropped
+    pub fn new<F: FnMut(DevQueryEvent) + Send + 'static>(
+        properties: &[PropertyKey],
+        filter: &FilterExpr,
+        callback: F,
+    ) -> WinResult<Self> {
+        let properties: Vec<_> = properties
+            .iter()
+            .map(|key| DEVPROPCOMPKEY {
+                Key: key.into_win32(),
+                Store: DEVPROP_STORE_SYSTEM,
+                LocaleName: PWSTR::null(),
+            })
+            .collect();
+        let filters = filter.win32_expressions();
+
+        let callback: Box<dyn FnMut(DevQueryEvent) + Send> = Box::new(callback);
+        let callback = Box::into_raw(Box::new(callback));
+
+        let mut handle = HDEVQUERY::default();
+        let result = unsafe {
+            DevCreateObjectQuery(
+                DevObjectTypeDeviceInterface,
+                DEV_QUERY_FLAGS(0),
+                &properties,
+                &filters,
+                Some(Self::win32_callback),
+                Some(callback as *const c_void),
+                &mut handle,
+            )
+        };
+        match result {
+            Ok(()) => Ok(Self { handle, callback }),
+            Err(e) => {
+                drop(unsafe { Box::from_raw(callback) });
+                Err(e)
+            },
+        }
+    }
+
+    unsafe extern "system" fn win32_callback(
+        _handle: HDEVQUERY,
+        context: *const c_void,
+        action_data: *const DEV_QUERY_RESULT_ACTION_DATA,
+    ) {
+        if action_data.is_null() {
+            return
+        }
+        if let Some(event) = DevQueryEvent::from_win32(&*action_data) {
+            let callback = &mut *(context as *mut Box<dyn FnMut(DevQueryEvent) + Send>);
+            callback(event);
+        }
+    }
+}
+
+impl Drop for DeviceQuery {
+    #[doc(alias = "DevCloseObjectQuery")]
+    fn drop(&mut self) {
+        unsafe { DevCloseObjectQuery(self.handle) };
+        drop(unsafe { Box::from_raw(self.callback) });
+    }
+}
+
+impl Debug for DeviceQuery {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("DeviceQuery").field("handle", &self.handle).finish()
+    }
+}
+
+unsafe impl Send for DeviceQuery {}
+
+/// A single device object returned from a [`DevObjectQuery`], along with its pre-fetched
+/// property values
+///
+/// Wraps a [`DEV_OBJECT`][wraps].
+///
+/// [wraps]: https://learn.microsoft.com/en-us/windows/win32/api/devquery/ns-devquery-dev_object
+pub struct DevObject {
+    object_type: DEV_OBJECT_TYPE,
+    id: Option<WideCString>,
+    properties: Vec<(PropertyKey, Property<'static>)>,
+}
+
+impl DevObject {
+    /// This object's device object type
+    pub const fn object_type(&self) -> DEV_OBJECT_TYPE {
+        self.object_type
+    }
+
+    /// This object's device interface path or instance ID, as reported by the query
+    pub fn id(&self) -> Option<&WideCStr> {
+        self.id.as_deref()
+    }
+
+    /// The pre-fetched properties requested of the query that produced this object
+    pub fn properties(&self) -> &[(PropertyKey, Property<'static>)] {
+        &self.properties
+    }
+
+    /// Look up a single pre-fetched property by key
+    pub fn property(&self, key: &PropertyKey) -> Option<&Property<'static>> {
+        self.properties.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    unsafe fn from_win32(object: DEV_OBJECT) -> Self {
+        let id = (!object.pszObjectId.is_null()).then(|| WideCString::from_ptr_str(object.pszObjectId.0));
+        let properties = if object.cPropertyCount == 0 || object.pProperties.is_null() {
+            Vec::new()
+        } else {
+            slice::from_raw_parts(object.pProperties, object.cPropertyCount as usize)
+                .iter()
+                .filter_map(|prop| {
+                    let type_ = PropertyTypeMod::try_from_win32(prop.Type).ok()?;
+                    let data = (!prop.Buffer.is_null() && prop.BufferSize > 0)
+                        .then(|| slice::from_raw_parts(prop.Buffer as *const u8, prop.BufferSize as usize).to_vec())
+                        .unwrap_or_default();
+                    Some((PropertyKey::from_win32(prop.CompKey.Key), Property::new(type_, data)))
+                })
+                .collect()
+        };
+        Self {
+            object_type: object.ObjectType,
+            id,
+            properties,
+        }
+    }
+}
+
+impl Debug for DevObject {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("DevObject")
+            .field("id", &self.id.as_ref().map(|id| id.to_string_lossy()))
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
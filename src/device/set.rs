@@ -7,8 +7,9 @@ use {
         fmt::{self, Debug, Formatter},
         mem,
     },
+    widestring::WideCStr,
     windows::{
-        core::Result as WinResult,
+        core::{Result as WinResult, PCWSTR},
         Win32::Devices::DeviceAndDriverInstallation::{
             self, SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo, SetupDiGetClassDevsExW, HDEVINFO,
             SP_DEVINFO_DATA,
@@ -65,9 +66,34 @@ impl InfoSet {
     /// [setupdigetclassdevsexw]: https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdigetclassdevsexw
     #[doc(alias = "SetupDiGetClassDevsExW")]
     pub fn new(class: &Guid, flags: InfoSetFlags) -> WinResult<Self> {
+        Self::new_ex(class, flags, None, None)
+    }
+
+    /// Create a new handle like [`new`](Self::new), additionally scoped to an `enumerator`
+    /// (e.g. a bus driver service name) and/or enumerated from a remote `machine`
+    /// (e.g. `\\HOST`) rather than the local one
+    ///
+    /// This is a wrapper around [`SetupDiGetClassDevsExW`][setupdigetclassdevsexw].
+    ///
+    /// [setupdigetclassdevsexw]: https://learn.microsoft.com/en-us/windows/win32/api/setupapi/nf-setupapi-setupdigetclassdevsexw
+    #[doc(alias = "SetupDiGetClassDevsExW")]
+    pub fn new_ex(
+        class: &Guid,
+        flags: InfoSetFlags,
+        enumerator: Option<&WideCStr>,
+        machine: Option<&WideCStr>,
+    ) -> WinResult<Self> {
         unsafe {
-            SetupDiGetClassDevsExW(Some(class.as_ref()), None, None, flags.bits(), None, None, None)
-                .map(|handle| Self::from_win32(handle))
+            SetupDiGetClassDevsExW(
+                Some(class.as_ref()),
+                enumerator.map(|e| PCWSTR(e.as_ptr())),
+                None,
+                flags.bits(),
+                None,
+                machine.map(|m| PCWSTR(m.as_ptr())),
+                None,
+            )
+            .map(|handle| Self::from_win32(handle))
         }
     }
 }
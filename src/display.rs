@@ -1,19 +1,29 @@
 #[cfg(doc)]
 use windows::Win32;
 use {
-    crate::win32::wide_str_from_slice_truncated,
+    crate::{
+        device::DevNode,
+        win32::{wide_str_from_slice_truncated, win32_error},
+    },
     std::{
         borrow::Cow,
         cmp::Ordering,
+        error,
         fmt::{self, Debug, Display, Formatter},
         hash::{Hash, Hasher},
         mem,
         ops::Deref,
     },
-    widestring::{widestr, WideCStr, WideStr},
+    widestring::{widestr, WideCStr, WideStr, WideString},
     windows::{
-        core::PCWSTR,
-        Win32::Graphics::Gdi::{self, EnumDisplayDevicesW, DISPLAY_DEVICEW},
+        core::{Result as WinResult, PCWSTR},
+        Win32::{
+            Foundation::ERROR_NOT_FOUND,
+            Graphics::Gdi::{
+                self, ChangeDisplaySettingsExW, EnumDisplayDevicesW, EnumDisplaySettingsExW, CDS_TYPE, DEVMODEW,
+                DISPLAY_DEVICEW, DISP_CHANGE, ENUM_CURRENT_SETTINGS,
+            },
+        },
     },
 };
 
@@ -57,6 +67,22 @@ impl DisplayDevice {
             .map(move |monitor| MonitorDevice::new(monitor, self))
     }
 
+    /// [Enumerate monitors](Self::enumerate_monitors), additionally setting
+    /// `EDD_GET_DEVICE_INTERFACE_NAME` so each monitor's
+    /// [`interface_path`](MonitorDevice::interface_path) can be read without a second
+    /// enumeration pass.
+    ///
+    /// This is a wrapper around [`EnumDisplayDevicesW`][enumdisplaydevicesw],
+    /// with [`self.name()`](Self::name) passed as `lpDevice`.
+    ///
+    /// [enumdisplaydevicesw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaydevicesw
+    #[doc(alias = "EnumDisplayDevicesW")]
+    pub fn enumerate_monitors_with_interface<'a>(&'a self) -> impl Iterator<Item = MonitorDevice<'a>> + 'a {
+        self.win32_enumerate_monitors(true)
+            .map(Self::from_win32)
+            .map(move |monitor| MonitorDevice::new(monitor, self))
+    }
+
     /// [Enumerate all monitors](Self::enumerate_monitors) for every [display
     /// device](Self::enumerate)
     #[doc(alias = "EnumDisplayDevicesW")]
@@ -64,6 +90,16 @@ impl DisplayDevice {
         Self::enumerate().flat_map(|display| display.enumerate_monitors().map(|mon| mon.owned()).collect::<Vec<_>>())
     }
 
+    /// [Enumerate](Self::enumerate) only the devices that are
+    /// [`ACTIVE`](DisplayDeviceFlags::ACTIVE) and not a
+    /// [`MIRRORING_DRIVER`](DisplayDeviceFlags::MIRRORING_DRIVER)
+    pub fn enumerate_active() -> impl Iterator<Item = Self> {
+        Self::enumerate().filter(|d| {
+            let flags = d.flags();
+            flags.contains(DisplayDeviceFlags::ACTIVE) && !flags.contains(DisplayDeviceFlags::MIRRORING_DRIVER)
+        })
+    }
+
     /// Not used
     pub fn id<'a>(&'a self) -> impl Display + Debug + 'a {
         self.win32_id().display()
@@ -95,6 +131,77 @@ impl DisplayDevice {
     pub fn is_monitor(&self) -> bool {
         self.win32_id().as_slice().starts_with(widestr!("MONITOR\\").as_slice())
     }
+
+    /// The first [display device](Self::enumerate) with
+    /// [`DisplayDeviceFlags::PRIMARY_DEVICE`] set
+    pub fn primary() -> Option<Self> {
+        Self::enumerate().find(|d| d.flags().contains(DisplayDeviceFlags::PRIMARY_DEVICE))
+    }
+
+    /// This display device's current display mode
+    ///
+    /// This is a wrapper around [`EnumDisplaySettingsExW`][enumdisplaysettingsexw],
+    /// passing `ENUM_CURRENT_SETTINGS` as `iModeNum`.
+    ///
+    /// [enumdisplaysettingsexw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaysettingsexw
+    #[doc(alias = "EnumDisplaySettingsExW")]
+    pub fn settings(&self) -> Option<DisplayMode> {
+        self.win32_enum_settings(ENUM_CURRENT_SETTINGS.0 as u32, 0)
+    }
+
+    /// One of the display modes supported by this display device, by index
+    ///
+    /// This is a wrapper around [`EnumDisplaySettingsExW`][enumdisplaysettingsexw].
+    /// Callers should increment `mode_index` from `0` until this returns `None`
+    /// to enumerate every supported mode, or use [`Self::enumerate_settings`].
+    ///
+    /// [enumdisplaysettingsexw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaysettingsexw
+    #[doc(alias = "EnumDisplaySettingsExW")]
+    pub fn settings_for(&self, mode_index: u32) -> Option<DisplayMode> {
+        self.win32_enum_settings(mode_index, 0)
+    }
+
+    /// All display modes supported by this display device
+    #[doc(alias = "EnumDisplaySettingsExW")]
+    pub fn enumerate_settings<'a>(&'a self) -> impl Iterator<Item = DisplayMode> + 'a {
+        (0..)
+            .map(move |i| self.settings_for(i))
+            .take_while(|d| d.is_some())
+            .filter_map(|d| d)
+    }
+
+    /// Apply a [`DisplayMode`]'s resolution, bit depth, and refresh rate to this display device
+    ///
+    /// This is a wrapper around [`ChangeDisplaySettingsExW`][changedisplaysettingsexw], setting
+    /// `dmFields` to the resolution/bit depth/refresh rate fields before the call. Pass
+    /// [`CdsFlags::FULLSCREEN`] for a temporary change such as a fullscreen window toggling its
+    /// resolution, or [`CdsFlags::UPDATE_REGISTRY`] to also save the change to the registry; with
+    /// no flags the mode is changed without being saved.
+    ///
+    /// [changedisplaysettingsexw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-changedisplaysettingsexw
+    #[doc(alias = "ChangeDisplaySettingsExW")]
+    pub fn change_settings(&self, mode: &DisplayMode, flags: CdsFlags) -> Result<(), ModeChangeError> {
+        const DM_PELSWIDTH: u32 = 0x0008_0000;
+        const DM_PELSHEIGHT: u32 = 0x0010_0000;
+        const DM_BITSPERPEL: u32 = 0x0004_0000;
+        const DM_DISPLAYFREQUENCY: u32 = 0x0040_0000;
+
+        let mut info = mode.into_win32();
+        info.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT | DM_BITSPERPEL | DM_DISPLAYFREQUENCY;
+
+        match unsafe {
+            ChangeDisplaySettingsExW(
+                self.win32_name_().map(|s| PCWSTR(s.as_ptr())),
+                Some(&info),
+                None,
+                CDS_TYPE(flags.bits()),
+                None,
+            )
+        } {
+            Gdi::DISP_CHANGE_SUCCESSFUL => Ok(()),
+            change => Err(ModeChangeError::from_disp_change(change)),
+        }
+    }
 }
 
 #[allow(missing_docs)]
@@ -159,6 +266,16 @@ impl DisplayDevice {
             .filter_map(|d| d)
     }
 
+    #[doc(alias = "EnumDisplaySettingsExW")]
+    pub fn win32_enum_settings(&self, mode_num: u32, flags: u32) -> Option<DisplayMode> {
+        let mut info = DEVMODEW::default();
+        info.dmSize = mem::size_of::<DEVMODEW>() as u16;
+        unsafe { EnumDisplaySettingsExW(self.win32_name_().map(|s| PCWSTR(s.as_ptr())), mode_num, &mut info, flags) }
+            .ok()
+            .map(|()| DisplayMode::from_win32(info))
+            .ok()
+    }
+
     #[doc(alias = "EnumDisplayDevicesW")]
     pub fn win32_enumerate_monitors<'a>(&'a self, interface_name: bool) -> impl Iterator<Item = DISPLAY_DEVICEW> + 'a {
         const EDD_GET_DEVICE_INTERFACE_NAME: u32 = 1;
@@ -297,6 +414,36 @@ impl<'a> MonitorDevice<'a> {
         &self.display
     }
 
+    /// This monitor's device interface path, e.g. `\\?\DISPLAY#...#{GUID}`
+    ///
+    /// This is the stable key used by `SetupAPI`, `CreateFile`, and the WinRT display stack to
+    /// identify a monitor. It is not part of the [`DISPLAY_DEVICEW`] returned by a plain
+    /// [`enumerate_monitors`](DisplayDevice::enumerate_monitors) call, so this re-enumerates
+    /// [`self.display()`](Self::display)'s monitors with
+    /// [`EDD_GET_DEVICE_INTERFACE_NAME`](DisplayDevice::enumerate_monitors_with_interface) set,
+    /// matching on [`win32_name`](DisplayDevice::win32_name) to find this monitor's entry.
+    pub fn interface_path(&self) -> Option<WideString> {
+        self.display
+            .win32_enumerate_monitors(true)
+            .find(|info| info.DeviceName == self.monitor.win32_info().DeviceName)
+            .map(|info| DisplayDevice::from_win32(info).win32_id().to_owned())
+    }
+
+    /// Resolve this monitor's PnP [`DevNode`], for reading live
+    /// [`PropertyKey`](crate::device::PropertyKey) values off the real hardware
+    ///
+    /// This is a thin wrapper around [`self.interface_path()`](Self::interface_path) and
+    /// [`DevNode::from_interface_path`].
+    pub fn device_node(&self) -> WinResult<DevNode> {
+        match self.interface_path() {
+            Some(path) => DevNode::from_interface_path(&path),
+            None => Err(win32_error(
+                ERROR_NOT_FOUND,
+                &format_args!("no device interface path for monitor {:?}", self.win32_name()),
+            )),
+        }
+    }
+
     /// Remove the pesky lifetime by copying the display device inline
     pub fn owned(&self) -> MonitorDevice<'static> {
         MonitorDevice {
@@ -369,3 +516,218 @@ bitflags::bitflags! {
         const VGA_COMPATIBLE = Gdi::DISPLAY_DEVICE_VGA_COMPATIBLE;
     }
 }
+
+bitflags::bitflags! {
+    /// Flags passed to [`DisplayDevice::change_settings`]
+    #[derive(Default)]
+    pub struct CdsFlags: u32 {
+        /// The mode change is temporary, reverting automatically when the calling process exits
+        ///
+        /// Use this for a fullscreen window's resolution change, rather than persisting it.
+        ///
+        /// See also: [`Gdi::CDS_FULLSCREEN`]
+        #[doc(alias = "CDS_FULLSCREEN")]
+        const FULLSCREEN = Gdi::CDS_FULLSCREEN.0;
+
+        /// The settings are saved in the global registry settings
+        ///
+        /// See also: [`Gdi::CDS_GLOBAL`]
+        #[doc(alias = "CDS_GLOBAL")]
+        const GLOBAL = Gdi::CDS_GLOBAL.0;
+
+        /// This device becomes the primary device
+        ///
+        /// See also: [`Gdi::CDS_SET_PRIMARY`]
+        #[doc(alias = "CDS_SET_PRIMARY")]
+        const SET_PRIMARY = Gdi::CDS_SET_PRIMARY.0;
+
+        /// The mode is changed dynamically and also saved to the registry
+        ///
+        /// See also: [`Gdi::CDS_UPDATEREGISTRY`]
+        #[doc(alias = "CDS_UPDATEREGISTRY")]
+        const UPDATE_REGISTRY = Gdi::CDS_UPDATEREGISTRY.0;
+
+        /// The settings are tested for validity, without being applied
+        ///
+        /// See also: [`Gdi::CDS_TEST`]
+        #[doc(alias = "CDS_TEST")]
+        const TEST = Gdi::CDS_TEST.0;
+
+        /// The settings are saved to the registry, but not applied
+        ///
+        /// Only valid together with [`UPDATE_REGISTRY`](Self::UPDATE_REGISTRY).
+        ///
+        /// See also: [`Gdi::CDS_NORESET`]
+        #[doc(alias = "CDS_NORESET")]
+        const NO_RESET = Gdi::CDS_NORESET.0;
+
+        /// The settings should be changed, even if the requested settings are the same as the current ones
+        ///
+        /// See also: [`Gdi::CDS_RESET`]
+        #[doc(alias = "CDS_RESET")]
+        const RESET = Gdi::CDS_RESET.0;
+    }
+}
+
+/// The unsuccessful result of a [`DisplayDevice::change_settings`] call
+///
+/// Mirrors the `DISP_CHANGE_*` constants returned by `ChangeDisplaySettingsExW`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[doc(alias = "DISP_CHANGE")]
+#[non_exhaustive]
+pub enum ModeChangeError {
+    /// The computer must be restarted for the graphics mode to work
+    #[doc(alias = "DISP_CHANGE_RESTART")]
+    RestartRequired,
+    /// The display driver failed the specified graphics mode
+    #[doc(alias = "DISP_CHANGE_FAILED")]
+    Failed,
+    /// The graphics mode is not supported
+    #[doc(alias = "DISP_CHANGE_BADMODE")]
+    BadMode,
+    /// Unable to write settings to the registry
+    #[doc(alias = "DISP_CHANGE_NOTUPDATED")]
+    NotUpdated,
+    /// An invalid set of flags was passed in
+    #[doc(alias = "DISP_CHANGE_BADFLAGS")]
+    BadFlags,
+    /// An invalid parameter was passed in, such as an invalid flag or combination of flags
+    #[doc(alias = "DISP_CHANGE_BADPARAM")]
+    BadParam,
+    /// The settings change was unsuccessful because the system is DualView capable
+    #[doc(alias = "DISP_CHANGE_BADDUALVIEW")]
+    BadDualView,
+    /// An undocumented `DISP_CHANGE` result code was returned
+    Other(i32),
+}
+
+impl ModeChangeError {
+    fn from_disp_change(change: DISP_CHANGE) -> Self {
+        match change {
+            Gdi::DISP_CHANGE_RESTART => Self::RestartRequired,
+            Gdi::DISP_CHANGE_FAILED => Self::Failed,
+            Gdi::DISP_CHANGE_BADMODE => Self::BadMode,
+            Gdi::DISP_CHANGE_NOTUPDATED => Self::NotUpdated,
+            Gdi::DISP_CHANGE_BADFLAGS => Self::BadFlags,
+            Gdi::DISP_CHANGE_BADPARAM => Self::BadParam,
+            Gdi::DISP_CHANGE_BADDUALVIEW => Self::BadDualView,
+            other => Self::Other(other.0),
+        }
+    }
+}
+
+impl Display for ModeChangeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::RestartRequired => f.write_str("the computer must be restarted for the graphics mode to work"),
+            Self::Failed => f.write_str("the display driver failed the specified graphics mode"),
+            Self::BadMode => f.write_str("the graphics mode is not supported"),
+            Self::NotUpdated => f.write_str("unable to write settings to the registry"),
+            Self::BadFlags => f.write_str("an invalid set of flags was passed in"),
+            Self::BadParam => f.write_str("an invalid parameter was passed in"),
+            Self::BadDualView => f.write_str("the settings change was unsuccessful because the system is DualView capable"),
+            Self::Other(code) => write!(f, "ChangeDisplaySettingsExW failed with result code {code}"),
+        }
+    }
+}
+
+impl error::Error for ModeChangeError {}
+
+/// A single display mode (resolution, refresh rate, orientation, ...) of a [`DisplayDevice`]
+///
+/// This wraps a [`DEVMODEW`][devmodew], restricted to the fields relevant to display settings.
+///
+/// See also: [`Win32::Graphics::Gdi::DEVMODEW`]
+///
+/// [devmodew]: https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-devmodew
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+#[doc(alias = "DEVMODEW")]
+pub struct DisplayMode {
+    info: DEVMODEW,
+}
+
+impl DisplayMode {
+    /// This mode's position on the virtual desktop, in pixels
+    #[doc(alias = "dmPosition")]
+    pub fn position(&self) -> (i32, i32) {
+        let position = unsafe { self.info.Anonymous1.Anonymous2.dmPosition };
+        (position.x, position.y)
+    }
+
+    /// This mode's resolution, in pixels
+    #[doc(alias = "dmPelsWidth")]
+    #[doc(alias = "dmPelsHeight")]
+    pub const fn resolution(&self) -> (u32, u32) {
+        (self.info.dmPelsWidth, self.info.dmPelsHeight)
+    }
+
+    /// This mode's refresh rate, in Hz
+    ///
+    /// A value of `0` or `1` represents the display's default refresh rate.
+    #[doc(alias = "dmDisplayFrequency")]
+    pub const fn refresh_rate(&self) -> u32 {
+        self.info.dmDisplayFrequency
+    }
+
+    /// The number of bits per pixel of this mode's color format
+    #[doc(alias = "dmBitsPerPel")]
+    pub const fn bits_per_pixel(&self) -> u32 {
+        self.info.dmBitsPerPel
+    }
+
+    /// This mode's orientation, as one of the `DMDO_*` constants
+    ///
+    /// See also: [`Win32::Graphics::Gdi::DMDO_DEFAULT`]
+    #[doc(alias = "dmDisplayOrientation")]
+    pub fn orientation(&self) -> u32 {
+        unsafe { self.info.Anonymous1.Anonymous2.dmDisplayOrientation }
+    }
+}
+
+#[allow(missing_docs)]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "win32")))]
+#[cfg_attr(not(feature = "win32"), doc(hidden))]
+impl DisplayMode {
+    pub const fn from_win32(info: DEVMODEW) -> Self {
+        Self { info }
+    }
+
+    pub const fn into_win32(self) -> DEVMODEW {
+        self.info
+    }
+
+    pub const fn win32_info(&self) -> &DEVMODEW {
+        &self.info
+    }
+}
+
+impl Debug for DisplayMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("DisplayMode")
+            .field("position", &self.position())
+            .field("resolution", &self.resolution())
+            .field("refresh_rate", &self.refresh_rate())
+            .field("bits_per_pixel", &self.bits_per_pixel())
+            .field("orientation", &self.orientation())
+            .finish()
+    }
+}
+
+impl AsRef<DEVMODEW> for DisplayMode {
+    fn as_ref(&self) -> &DEVMODEW {
+        &self.info
+    }
+}
+
+impl From<DisplayMode> for DEVMODEW {
+    fn from(info: DisplayMode) -> Self {
+        info.into_win32()
+    }
+}
+
+impl From<DEVMODEW> for DisplayMode {
+    fn from(info: DEVMODEW) -> Self {
+        Self::from_win32(info)
+    }
+}
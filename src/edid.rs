@@ -0,0 +1,267 @@
+//! [Extended Display Identification Data][edid] extraction and parsing
+//!
+//! Windows does not expose a monitor's EDID through a dedicated API; instead it is cached by the
+//! display driver under the device's `Device Parameters` registry key. This mirrors how
+//! `monitor-control-win` reaches the raw EDID through the registry.
+//!
+//! [edid]: https://en.wikipedia.org/wiki/Extended_Display_Identification_Data
+
+use {
+    crate::{registry::Key, win32::win32_error, MonitorDevice},
+    widestring::{widecstr, WideCString, WideStr},
+    windows::{
+        core::Result as WinResult,
+        Win32::{
+            Foundation::{ERROR_FILE_NOT_FOUND, ERROR_INVALID_DATA},
+            System::Registry::{KEY_READ, REG_OPEN_CREATE_OPTIONS},
+        },
+    },
+};
+
+const HEADER: [u8; 8] = [0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00];
+const DESCRIPTOR_OFFSET: usize = 54;
+const DESCRIPTOR_LEN: usize = 18;
+const DESCRIPTOR_COUNT: usize = 4;
+const EXTENSION_COUNT_OFFSET: usize = 126;
+
+/// A decoded [detailed timing descriptor][dtd], one of the possible contents of an EDID
+/// [`Descriptor`] block
+///
+/// Only the pixel clock and active/blanking pixel counts are decoded; sync timing, image size,
+/// and border/interlace flags in the remaining bytes are not currently exposed.
+///
+/// [dtd]: https://en.wikipedia.org/wiki/Extended_Display_Identification_Data#Detailed_timing_descriptor
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DetailedTiming {
+    /// The pixel clock, in kHz
+    pub pixel_clock_khz: u32,
+    /// Horizontal addressable pixels
+    pub h_active: u16,
+    /// Horizontal blanking pixels
+    pub h_blank: u16,
+    /// Vertical addressable lines
+    pub v_active: u16,
+    /// Vertical blanking lines
+    pub v_blank: u16,
+}
+
+impl DetailedTiming {
+    fn parse(d: &[u8]) -> Self {
+        Self {
+            pixel_clock_khz: u16::from_le_bytes([d[0], d[1]]) as u32 * 10,
+            h_active: d[2] as u16 | ((d[4] >> 4) as u16) << 8,
+            h_blank: d[3] as u16 | ((d[4] & 0x0f) as u16) << 8,
+            v_active: d[5] as u16 | ((d[7] >> 4) as u16) << 8,
+            v_blank: d[6] as u16 | ((d[7] & 0x0f) as u16) << 8,
+        }
+    }
+}
+
+/// One of the four 18-byte monitor descriptor blocks at offsets 54/72/90/108 of the EDID base
+/// block
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum Descriptor {
+    /// A detailed timing descriptor (the first two bytes are a non-zero pixel clock)
+    Timing(DetailedTiming),
+    /// The monitor's serial number, as ASCII text (tag `0xff`)
+    SerialNumber(String),
+    /// Unspecified ASCII text (tag `0xfe`)
+    UnspecifiedText(String),
+    /// The monitor's name, as ASCII text (tag `0xfc`)
+    MonitorName(String),
+    /// Monitor range limits (tags `0xfd`/`0xfb`), exposed as the raw descriptor bytes 5-17;
+    /// not decoded further
+    RangeLimits([u8; DESCRIPTOR_LEN - 5]),
+    /// A descriptor tag this crate does not decode
+    Unknown(u8),
+}
+
+/// A parsed and validated [EDID][edid] base block
+///
+/// This only contains the 128-byte base block; extension blocks are not parsed.
+///
+/// [edid]: https://en.wikipedia.org/wiki/Extended_Display_Identification_Data
+#[derive(Copy, Clone)]
+pub struct Edid {
+    data: [u8; 128],
+}
+
+impl Edid {
+    /// Validate and parse an EDID base block
+    ///
+    /// Fails with [`ERROR_INVALID_DATA`] if `data` is shorter than 128 bytes, does not start
+    /// with the EDID header, or fails the base block checksum.
+    pub fn parse(data: &[u8]) -> WinResult<Self> {
+        let data: [u8; 128] = data
+            .get(..128)
+            .ok_or_else(|| win32_error(ERROR_INVALID_DATA, &format_args!("EDID base block is shorter than 128 bytes")))?
+            .try_into()
+            .unwrap();
+        if data[..HEADER.len()] != HEADER {
+            return Err(win32_error(ERROR_INVALID_DATA, &format_args!("EDID base block has an invalid header")))
+        }
+        if data.iter().fold(0u8, |sum, &b| sum.wrapping_add(b)) != 0 {
+            return Err(win32_error(ERROR_INVALID_DATA, &format_args!("EDID base block fails its checksum")))
+        }
+        Ok(Self { data })
+    }
+
+    /// The raw 128-byte EDID base block
+    pub fn data(&self) -> &[u8; 128] {
+        &self.data
+    }
+
+    /// The three-letter manufacturer ID, decoded from bytes 8-9
+    pub fn manufacturer_id(&self) -> String {
+        let id = u16::from_be_bytes([self.data[8], self.data[9]]);
+        [(id >> 10) & 0x1f, (id >> 5) & 0x1f, id & 0x1f]
+            .into_iter()
+            .map(|c| (b'A' - 1 + c as u8) as char)
+            .collect()
+    }
+
+    /// The manufacturer's product code, decoded from bytes 10-11
+    pub fn product_code(&self) -> u16 {
+        u16::from_le_bytes([self.data[10], self.data[11]])
+    }
+
+    /// The manufacturer's serial number, decoded from bytes 12-15
+    pub fn serial(&self) -> u32 {
+        u32::from_le_bytes([self.data[12], self.data[13], self.data[14], self.data[15]])
+    }
+
+    /// The week of manufacture, decoded from byte 16
+    pub fn manufacture_week(&self) -> u8 {
+        self.data[16]
+    }
+
+    /// The year of manufacture, decoded from byte 17
+    pub fn manufacture_year(&self) -> u16 {
+        self.data[17] as u16 + 1990
+    }
+
+    /// The EDID structure version and revision, decoded from bytes 18-19
+    pub fn version(&self) -> (u8, u8) {
+        (self.data[18], self.data[19])
+    }
+
+    /// The number of 128-byte extension blocks following the base block, decoded from byte 126
+    ///
+    /// Extension blocks aren't fetched or parsed by this type; callers that need them can read
+    /// `128 * (1..=self.extension_count())` byte offsets themselves (e.g. via
+    /// [`Self::read_from`]'s underlying [`ddc::Edid::read_edid`]).
+    pub fn extension_count(&self) -> u8 {
+        self.data[EXTENSION_COUNT_OFFSET]
+    }
+
+    /// Iterate over the four monitor descriptor blocks at bytes 54/72/90/108
+    pub fn descriptors(&self) -> impl Iterator<Item = Descriptor> + '_ {
+        (0..DESCRIPTOR_COUNT).map(move |i| {
+            let start = DESCRIPTOR_OFFSET + i * DESCRIPTOR_LEN;
+            let block = &self.data[start..start + DESCRIPTOR_LEN];
+            match block {
+                [0, 0, _, tag, _, text @ ..] => match *tag {
+                    0xff => Descriptor::SerialNumber(ascii_descriptor_text(text)),
+                    0xfe => Descriptor::UnspecifiedText(ascii_descriptor_text(text)),
+                    0xfc => Descriptor::MonitorName(ascii_descriptor_text(text)),
+                    0xfd | 0xfb => Descriptor::RangeLimits(text.try_into().unwrap()),
+                    tag => Descriptor::Unknown(tag),
+                },
+                _ => Descriptor::Timing(DetailedTiming::parse(block)),
+            }
+        })
+    }
+
+    /// The monitor's name, if an ASCII monitor name descriptor (tag `0xfc`) is present
+    pub fn model_name(&self) -> Option<String> {
+        self.descriptors().find_map(|d| match d {
+            Descriptor::MonitorName(name) => Some(name),
+            _ => None,
+        })
+    }
+
+    /// The monitor's serial number string, if an ASCII serial descriptor (tag `0xff`) is present
+    pub fn serial_string(&self) -> Option<String> {
+        self.descriptors().find_map(|d| match d {
+            Descriptor::SerialNumber(serial) => Some(serial),
+            _ => None,
+        })
+    }
+
+    /// Read and parse a monitor's EDID base block via [`ddc::Edid::read_edid`]
+    ///
+    /// This is the generic counterpart to [`Self::read_from_registry`] - `device` is typically a
+    /// [`DeviceInfo`](crate::DeviceInfo), but any `ddc::Edid` implementation using
+    /// [`windows::core::Error`] works. Extension blocks beyond the 128-byte base block are not
+    /// fetched; see [`Self::extension_count`].
+    pub fn read_from<D: ddc::Edid<EdidError = windows::core::Error>>(device: &mut D) -> WinResult<Self> {
+        let mut data = [0u8; 128];
+        let mut offset = 0usize;
+        while offset < data.len() {
+            match device.read_edid(offset as u8, &mut data[offset..])? {
+                0 => break,
+                n => offset += n,
+            }
+        }
+        Self::parse(&data[..offset])
+    }
+
+    /// Read and parse a monitor's EDID from the registry
+    ///
+    /// This walks `HKEY_LOCAL_MACHINE\SYSTEM\CurrentControlSet\Enum\DISPLAY` using
+    /// [`monitor.win32_id()`](MonitorDevice::win32_id) to find the manufacturer/product subkey,
+    /// then descends into each instance's `Device Parameters` subkey looking for the `EDID`
+    /// binary value.
+    pub fn read_from_registry(monitor: &MonitorDevice) -> WinResult<Option<Self>> {
+        read_edid_bytes(monitor.win32_id())?.map(|data| Self::parse(&data)).transpose()
+    }
+}
+
+fn ascii_descriptor_text(text: &[u8]) -> String {
+    let end = text.iter().position(|&b| b == 0x0a).unwrap_or(text.len());
+    String::from_utf8_lossy(&text[..end]).trim_end().to_owned()
+}
+
+fn read_edid_bytes(device_id: &WideStr) -> WinResult<Option<Vec<u8>>> {
+    let id = device_id.to_string_lossy();
+    let mut parts = id.split('\\');
+    let mfg_product = match (parts.next(), parts.next()) {
+        (Some("MONITOR"), Some(mfg_product)) => mfg_product,
+        _ => return Ok(None),
+    };
+    let mfg_product = WideCString::from_str(mfg_product)
+        .map_err(|e| win32_error(ERROR_INVALID_DATA, &format_args!("invalid monitor device id: {e:?}")))?;
+
+    let enum_key = match Key::HKEY_LOCAL_MACHINE.win32_open(
+        widecstr!("SYSTEM\\CurrentControlSet\\Enum\\DISPLAY"),
+        REG_OPEN_CREATE_OPTIONS(0),
+        KEY_READ,
+    ) {
+        Ok(key) => key,
+        Err(e) if e.code() == ERROR_FILE_NOT_FOUND.to_hresult() => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mfg_key = match enum_key.win32_open(&mfg_product, REG_OPEN_CREATE_OPTIONS(0), KEY_READ) {
+        Ok(key) => key,
+        Err(e) if e.code() == ERROR_FILE_NOT_FOUND.to_hresult() => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    for instance in mfg_key.win32_enumerate_keys() {
+        let (name, ..) = instance?;
+        let instance_key = match mfg_key.win32_open(&name, REG_OPEN_CREATE_OPTIONS(0), KEY_READ) {
+            Ok(key) => key,
+            Err(_) => continue,
+        };
+        let params_key =
+            match instance_key.win32_open(widecstr!("Device Parameters"), REG_OPEN_CREATE_OPTIONS(0), KEY_READ) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+        if let Ok((_, data)) = params_key.win32_query_value(widecstr!("EDID")) {
+            return Ok(Some(data))
+        }
+    }
+    Ok(None)
+}
@@ -4,6 +4,7 @@ use {
         fmt::{self, Debug, Display, Formatter},
         mem,
         ops::{Deref, DerefMut},
+        str::FromStr,
     },
     windows::core::GUID,
 };
@@ -33,6 +34,60 @@ impl Guid {
     }
 }
 
+impl Guid {
+    /// Parse the braced, hyphenated textual representation produced by [`Display`]
+    ///
+    /// The surrounding braces are optional.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')).unwrap_or(s);
+        let mut parts = s.splitn(5, '-');
+        let data1 = u32::from_str_radix(parts.next()?, 16).ok()?;
+        let data2 = u16::from_str_radix(parts.next()?, 16).ok()?;
+        let data3 = u16::from_str_radix(parts.next()?, 16).ok()?;
+        let data4a = parts.next()?;
+        let data4b = parts.next()?;
+        if parts.next().is_some() || data4a.len() != 4 || data4b.len() != 12 {
+            return None
+        }
+        let mut data4 = [0u8; 8];
+        for (byte, chunk) in data4.iter_mut().zip(data4a.as_bytes().chunks(2).chain(data4b.as_bytes().chunks(2))) {
+            *byte = u8::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+        }
+        Some(Self::from_win32(GUID { data1, data2, data3, data4 }))
+    }
+
+    /// Construct a GUID from its 128-bit integer representation
+    pub const fn from_u128(uuid: u128) -> Self {
+        Self::from_win32(GUID::from_u128(uuid))
+    }
+
+    /// This GUID's 128-bit integer representation
+    pub const fn to_u128(&self) -> u128 {
+        self.guid.to_u128()
+    }
+}
+
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or(ParseGuidError(()))
+    }
+}
+
+/// The error returned by a failed [`Guid`] [`FromStr::from_str`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ParseGuidError(());
+
+impl Display for ParseGuidError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid GUID string")
+    }
+}
+
+impl std::error::Error for ParseGuidError {}
+
 impl Display for Guid {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let [d0, d1, d2, d3, d4, d5, d6, d7] = self.guid.data4;
@@ -93,3 +148,31 @@ impl From<GUID> for Guid {
         Self::from_win32(g)
     }
 }
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl serde::Serialize for Guid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Guid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Guid;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a GUID string")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Guid, E> {
+                Guid::parse(v).ok_or_else(|| E::custom(format_args!("invalid GUID: {v:?}")))
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
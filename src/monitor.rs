@@ -2,8 +2,9 @@
 use windows::Win32;
 use {
     crate::{
-        win32::{borrow_unaligned, wide_str_from_slice_truncated},
-        Output,
+        device::{DevNode, Info, InfoSet},
+        win32::{borrow_unaligned, wide_str_from_slice_truncated, win32_error},
+        Capabilities, DisplayDevice, MonitorDevice, Output,
     },
     ddc::{Ddc, DdcHost, FeatureCode, TimingMessage, VcpValue},
     std::{
@@ -22,7 +23,7 @@ use {
                 GetTimingReport, GetVCPFeatureAndVCPFeatureReply, SaveCurrentSettings, SetVCPFeature, MC_MOMENTARY,
                 MC_SET_PARAMETER, MC_TIMING_REPORT, MC_VCP_CODE_TYPE, PHYSICAL_MONITOR,
             },
-            Foundation::{BOOL, HANDLE},
+            Foundation::{BOOL, ERROR_INVALID_DATA, ERROR_NOT_FOUND, HANDLE},
         },
     },
 };
@@ -58,11 +59,169 @@ impl Monitor {
         })
     }
 
+    /// [Enumerate all connected physical monitors](Self::enumerate), paired with their
+    /// associated [`MonitorDevice`], when one can be determined
+    ///
+    /// This is a convenience wrapper around [`Output::enumerate`]
+    /// and [`Output::enumerate_monitors_with_devices`].
+    pub fn enumerate_with_devices() -> WinResult<impl Iterator<Item = (WinResult<Self>, Option<MonitorDevice<'static>>)>>
+    {
+        Output::enumerate().map(|outputs| {
+            outputs.flat_map(|output| match output.enumerate_monitors_with_devices() {
+                Ok(monitors) => monitors.map(|(m, d)| (Ok(m), d)).collect::<Vec<_>>(),
+                Err(e) => vec![(Err(e), None)],
+            })
+        })
+    }
+
     /// Physical monitor description string.
     #[doc(alias = "szPhysicalMonitorDescription")]
     pub fn description<'a>(&'a self) -> impl Display + Debug + 'a {
         self.win32_description().to_string_lossy() // TODO: wrap .display()
     }
+
+    /// Best-effort correlation of this physical monitor with its [`MonitorDevice`]
+    ///
+    /// Neither `GetPhysicalMonitorsFromHMONITOR` nor `EnumDisplayDevices` expose a direct link
+    /// between a [`PHYSICAL_MONITOR`] and its PnP device, so this matches
+    /// [`self.description()`](Self::description) against each enumerated monitor device's
+    /// [`win32_string`](DisplayDevice::win32_string) (`DeviceString`), which Windows populates
+    /// from the same monitor friendly name.
+    pub fn device(&self) -> Option<MonitorDevice<'static>> {
+        let description = self.win32_description();
+        DisplayDevice::enumerate_all_monitors().find(|m| m.win32_string() == &*description)
+    }
+
+    /// [`self.device()`](Self::device), then [resolve its `DevNode`](MonitorDevice::device_node)
+    pub fn device_node(&self) -> WinResult<DevNode> {
+        match self.device() {
+            Some(device) => device.device_node(),
+            None => Err(win32_error(
+                ERROR_NOT_FOUND,
+                &format_args!("could not correlate physical monitor {:?} with a device", self.description()),
+            )),
+        }
+    }
+
+    /// Best-effort correlation of this physical monitor with its SetupAPI [`Info`]
+    ///
+    /// Unlike [`self.device_node()`](Self::device_node), which resolves through Config Manager,
+    /// this walks [`InfoSet::monitors()`] and matches each entry against
+    /// [`self.device()`](Self::device) via [`Info::matches_device`].
+    pub fn info(&self) -> WinResult<Info<'static>> {
+        let device = self.device().ok_or_else(|| {
+            win32_error(
+                ERROR_NOT_FOUND,
+                &format_args!("could not correlate physical monitor {:?} with a device", self.description()),
+            )
+        })?;
+
+        InfoSet::monitors()?
+            .enumerate_static()
+            .filter_map(|info| info.ok())
+            .find(|info| info.matches_device(&device).unwrap_or(false))
+            .ok_or_else(|| {
+                win32_error(
+                    ERROR_NOT_FOUND,
+                    &format_args!("could not correlate physical monitor {:?} with a SetupAPI device", self.description()),
+                )
+            })
+    }
+
+    /// Read this monitor's raw cached EDID off its PnP devnode
+    ///
+    /// This first tries [`self.device_node()`](Self::device_node), then
+    /// [`self.info()`](Self::info); if the monitor cannot be directly correlated with either,
+    /// it falls back to [`DevNode::find_monitor`] and returns the EDID of the first monitor
+    /// interface that reads successfully.
+    pub fn edid(&self) -> WinResult<Vec<u8>> {
+        if let Ok(node) = self.device_node() {
+            if let Ok(edid) = node.read_edid() {
+                return Ok(edid)
+            }
+        }
+
+        if let Ok(info) = self.info() {
+            if let Ok(edid) = info.edid() {
+                return Ok(edid)
+            }
+        }
+
+        DevNode::find_monitor()?
+            .filter_map(|node| node.ok())
+            .find_map(|node| node.read_edid().ok())
+            .ok_or_else(|| {
+                win32_error(
+                    ERROR_NOT_FOUND,
+                    &format_args!("could not read EDID for physical monitor {:?}", self.description()),
+                )
+            })
+    }
+
+    /// [Request](Self::win32_capabilities) and parse this monitor's DDC/CI capabilities string
+    ///
+    /// See [`Capabilities`] for the decoded grammar.
+    pub fn parse_capabilities(&self) -> WinResult<Capabilities> {
+        let raw = self.win32_capabilities()?;
+        Capabilities::parse(&raw).ok_or_else(|| {
+            win32_error(
+                ERROR_INVALID_DATA,
+                &format_args!("could not parse capabilities string for physical monitor {:?}", self.description()),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "winrt")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "winrt")))]
+impl Monitor {
+    /// Best-effort correlation of this physical monitor with its WinRT
+    /// [`winrt::DisplayMonitor`](crate::winrt::DisplayMonitor)
+    ///
+    /// This is [`self.device()`](Self::device), then
+    /// [`winrt::DisplayMonitor::from_monitor`](crate::winrt::DisplayMonitor::from_monitor).
+    pub fn winrt_monitor(&self) -> WinResult<crate::winrt::DisplayMonitor> {
+        let device = self.device().ok_or_else(|| {
+            win32_error(
+                ERROR_NOT_FOUND,
+                &format_args!("could not correlate physical monitor {:?} with a device", self.description()),
+            )
+        })?;
+
+        crate::winrt::DisplayMonitor::from_monitor(&device)?.ok_or_else(|| {
+            win32_error(
+                ERROR_NOT_FOUND,
+                &format_args!(
+                    "could not resolve a WinRT DisplayMonitor for physical monitor {:?}",
+                    self.description()
+                ),
+            )
+        })
+    }
+
+    /// A friendly, human-readable name for this monitor, e.g. "DELL U2720Q"
+    ///
+    /// This is a wrapper around
+    /// [`winrt::DisplayMonitor::display_name`](crate::winrt::DisplayMonitor::display_name).
+    pub fn display_name(&self) -> WinResult<String> {
+        self.winrt_monitor()?.display_name()
+    }
+
+    /// How this monitor is physically connected
+    ///
+    /// This is a wrapper around
+    /// [`winrt::DisplayMonitor::connection_kind`](crate::winrt::DisplayMonitor::connection_kind).
+    pub fn connection_kind(&self) -> WinResult<crate::winrt::ConnectionKind> {
+        self.winrt_monitor()?.connection_kind()
+    }
+
+    /// The physical connector used by this monitor
+    ///
+    /// This is a wrapper around
+    /// [`winrt::DisplayMonitor::physical_connector`](crate::winrt::DisplayMonitor::physical_connector).
+    pub fn physical_connector(&self) -> WinResult<crate::winrt::PhysicalConnector> {
+        self.winrt_monitor()?.physical_connector()
+    }
 }
 
 #[allow(missing_docs)]
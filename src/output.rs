@@ -1,10 +1,13 @@
 #[cfg(doc)]
 use windows::Win32;
 use {
-    crate::{win32::wide_str_from_slice_truncated, DisplayDevice, Monitor},
+    crate::{
+        win32::{wide_str_from_slice_truncated, win32_error},
+        CdsFlags, DisplayDevice, DisplayDeviceFlags, DisplayMode, ModeChangeError, Monitor, MonitorDevice,
+    },
     std::{
         fmt::{self, Debug, Display, Formatter},
-        mem, ptr,
+        iter, mem, ptr,
     },
     widestring::WideStr,
     windows::{
@@ -13,8 +16,9 @@ use {
             Devices::Display::{
                 GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR, PHYSICAL_MONITOR,
             },
-            Foundation::{BOOL, LPARAM, RECT},
+            Foundation::{BOOL, ERROR_NOT_FOUND, LPARAM, RECT},
             Graphics::Gdi::{EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITORINFO, MONITORINFOEXW},
+            UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
         },
     },
 };
@@ -54,6 +58,99 @@ impl Output {
         self.win32_physical_monitors()
             .map(|m| m.into_iter().map(|h| unsafe { Monitor::from_win32(h) }))
     }
+
+    /// This output's effective DPI, as an `(x, y)` pair
+    ///
+    /// This is a wrapper around [`GetDpiForMonitor`][getdpiformonitor], which requires
+    /// Windows 8.1 or later; if the call fails this falls back to the default of 96 DPI. The
+    /// function is imported directly rather than looked up at runtime, so this fallback does not
+    /// cover systems that lack it.
+    ///
+    /// [getdpiformonitor]: https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-getdpiformonitor
+    #[doc(alias = "GetDpiForMonitor")]
+    pub fn dpi(&self) -> (u32, u32) {
+        self.win32_dpi().unwrap_or((96, 96))
+    }
+
+    /// This output's [DPI](Self::dpi), expressed as a scale factor relative to the default of
+    /// 96 DPI
+    pub fn scale_factor(&self) -> f64 {
+        let (dpi_x, _) = self.dpi();
+        dpi_x as f64 / 96.0
+    }
+
+    /// The [display device](DisplayDevice) whose adapter name matches this output, if any
+    pub fn adapter(&self) -> Option<DisplayDevice> {
+        let info = self.info().ok()?;
+        DisplayDevice::enumerate().find(|d| info.device_matches_display(d))
+    }
+
+    /// The [monitor devices](MonitorDevice) attached to [this output's adapter](Self::adapter)
+    ///
+    /// This is how an [`HMONITOR`]-based [`Output`] can be bridged to the native monitor
+    /// identity (interface path, readable name) that [`DisplayDevice`] exposes.
+    #[doc(alias = "EnumDisplayDevicesW")]
+    pub fn display_devices(&self) -> impl Iterator<Item = MonitorDevice<'static>> {
+        self.adapter()
+            .map(|display| display.enumerate_monitors_with_interface().map(|m| m.owned()).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+    }
+
+    /// [This output's adapter](Self::adapter)'s current [`DisplayMode`]
+    ///
+    /// This reports the adapter's current resolution, bit depth, and refresh rate; for the
+    /// output's position and size on the virtual desktop, see
+    /// [`OutputInfo::position`]/[`OutputInfo::size`].
+    pub fn current_mode(&self) -> Option<DisplayMode> {
+        self.adapter()?.settings()
+    }
+
+    /// All display modes supported by [this output's adapter](Self::adapter)
+    pub fn enumerate_modes(&self) -> Option<impl Iterator<Item = DisplayMode>> {
+        Some(self.adapter()?.enumerate_settings().collect::<Vec<_>>().into_iter())
+    }
+
+    /// Apply a [`DisplayMode`] to [this output's adapter](Self::adapter)
+    ///
+    /// Returns `None` if this output has no adapter to apply the mode to. See
+    /// [`DisplayDevice::change_settings`] for details on `flags`.
+    pub fn set_mode(&self, mode: &DisplayMode, flags: CdsFlags) -> Option<Result<(), ModeChangeError>> {
+        Some(self.adapter()?.change_settings(mode, flags))
+    }
+
+    /// [Set](Self::set_mode) a temporary, non-persisted mode change suitable for a fullscreen
+    /// window toggling its resolution
+    pub fn set_fullscreen_mode(&self, mode: &DisplayMode) -> Option<Result<(), ModeChangeError>> {
+        self.set_mode(mode, CdsFlags::FULLSCREEN)
+    }
+
+    /// [This output's adapter](Self::adapter)'s [`DisplayDeviceFlags`]
+    ///
+    /// This is how callers can tell whether this output is the primary device, among other
+    /// adapter state.
+    pub fn state_flags(&self) -> Option<DisplayDeviceFlags> {
+        Some(self.adapter()?.flags())
+    }
+
+    /// [This output's physical monitors](Self::enumerate_monitors), paired with the
+    /// corresponding [`MonitorDevice`] from [`self.adapter()`](Self::adapter), when one can be
+    /// determined
+    ///
+    /// This pairs physical monitors with monitor devices strictly by enumeration order on the
+    /// matching adapter; Windows does not document that order as stable, so this remains a
+    /// best-effort identification, not a guaranteed one. Callers that need a stable per-monitor
+    /// key should use [`Monitor::device_node`] instead.
+    pub fn enumerate_monitors_with_devices(
+        &self,
+    ) -> WinResult<impl Iterator<Item = (Monitor, Option<MonitorDevice<'static>>)>> {
+        let devices = self
+            .adapter()
+            .map(|display| display.enumerate_monitors().map(|m| m.owned()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        self.enumerate_monitors()
+            .map(move |monitors| monitors.zip(devices.into_iter().map(Some).chain(iter::repeat(None))))
+    }
 }
 
 #[allow(missing_docs)]
@@ -105,6 +202,35 @@ impl Output {
 
         Ok(monitors)
     }
+
+    #[doc(alias = "GetDpiForMonitor")]
+    pub fn win32_dpi(&self) -> WinResult<(u32, u32)> {
+        let (mut dpi_x, mut dpi_y) = (0u32, 0u32);
+        unsafe { GetDpiForMonitor(self.handle, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) }?;
+        Ok((dpi_x, dpi_y))
+    }
+}
+
+#[cfg(feature = "winrt")]
+#[cfg_attr(feature = "doc", doc(cfg(feature = "winrt")))]
+impl Output {
+    /// Resolve the WinRT [`DisplayMonitor`](crate::winrt::DisplayMonitor) for this output
+    ///
+    /// This is [`self.display_devices()`](Self::display_devices), then
+    /// [`winrt::DisplayMonitor::from_monitor`](crate::winrt::DisplayMonitor::from_monitor) on
+    /// the first monitor device found.
+    pub fn display_monitor(&self) -> WinResult<crate::winrt::DisplayMonitor> {
+        let device = self.display_devices().next().ok_or_else(|| {
+            win32_error(ERROR_NOT_FOUND, &format_args!("could not correlate output {:?} with a monitor device", self))
+        })?;
+
+        crate::winrt::DisplayMonitor::from_monitor(&device)?.ok_or_else(|| {
+            win32_error(
+                ERROR_NOT_FOUND,
+                &format_args!("could not resolve a WinRT DisplayMonitor for output {:?}", self),
+            )
+        })
+    }
 }
 
 impl Debug for Output {
@@ -165,6 +291,59 @@ impl OutputInfo {
         self.win32_device_name().display()
     }
 
+    /// This output's position on the virtual desktop, in pixels
+    #[doc(alias = "rcMonitor")]
+    pub fn position(&self) -> (i32, i32) {
+        let rect = self.win32_monitor_area();
+        (rect.left, rect.top)
+    }
+
+    /// This output's size, in pixels
+    #[doc(alias = "rcMonitor")]
+    pub fn size(&self) -> (u32, u32) {
+        let rect = self.win32_monitor_area();
+        ((rect.right - rect.left) as u32, (rect.bottom - rect.top) as u32)
+    }
+
+    /// This output's work area's position on the virtual desktop, in pixels
+    ///
+    /// The work area excludes space taken up by the taskbar and other docked UI.
+    #[doc(alias = "rcWork")]
+    pub fn work_area_position(&self) -> (i32, i32) {
+        let rect = self.win32_work_area();
+        (rect.left, rect.top)
+    }
+
+    /// This output's work area's size, in pixels
+    ///
+    /// The work area excludes space taken up by the taskbar and other docked UI.
+    #[doc(alias = "rcWork")]
+    pub fn work_area_size(&self) -> (u32, u32) {
+        let rect = self.win32_work_area();
+        ((rect.right - rect.left) as u32, (rect.bottom - rect.top) as u32)
+    }
+
+    /// Whether `point`, in virtual desktop pixel coordinates, falls within
+    /// [this output's bounds](Self::position)/[size](Self::size)
+    pub fn contains(&self, point: (i32, i32)) -> bool {
+        let rect = self.win32_monitor_area();
+        (rect.left..rect.right).contains(&point.0) && (rect.top..rect.bottom).contains(&point.1)
+    }
+
+    /// A human-readable name for this monitor, suitable for showing to a user
+    ///
+    /// [`Self::device_name`] only returns the opaque `\\.\DISPLAYn` GDI device name; this instead
+    /// finds the [`DisplayDevice`] whose adapter matches this output and reads the
+    /// [`string()`](DisplayDevice::string) of the first monitor attached to it, falling back to
+    /// the adapter's own `string()` if it has no monitor devices.
+    pub fn readable_name(&self) -> Option<String> {
+        let display = DisplayDevice::enumerate().find(|d| self.device_matches_display(d))?;
+        Some(match display.enumerate_monitors().next() {
+            Some(monitor) => monitor.string().to_string(),
+            None => display.string().to_string(),
+        })
+    }
+
     /// Whether this [output](Output) is part of the specified [display device](DisplayDevice)
     ///
     /// Note that a [monitor device](crate::MonitorDevice)
@@ -0,0 +1,12 @@
+//! [Windows Registry][registry] access
+//!
+//! Exposes a thin, safe wrapper around `HKEY` along with higher-level helpers such as
+//! [`DisplayChangeWatcher`] that are built on top of it.
+//!
+//! [registry]: https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry
+
+pub use self::{key::Key, value::RegistryValue, watch::DisplayChangeWatcher};
+
+mod key;
+mod value;
+mod watch;
@@ -0,0 +1,93 @@
+//! Typed decoding of raw registry value bytes
+
+use {
+    crate::{registry::Key, win32::win32_error},
+    widestring::{WideCStr, WideCString},
+    windows::{
+        core::Result as WinResult,
+        Win32::{
+            Foundation::ERROR_INVALID_DATA,
+            System::Registry::{
+                REG_DWORD, REG_DWORD_BIG_ENDIAN, REG_EXPAND_SZ, REG_MULTI_SZ, REG_QWORD, REG_SZ, REG_VALUE_TYPE,
+            },
+        },
+    },
+};
+
+/// A registry value, decoded according to its [`REG_VALUE_TYPE`]
+///
+/// See [`Key::query_value`] and [`Key::values`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RegistryValue {
+    /// `REG_SZ`/`REG_EXPAND_SZ`: a string, with the trailing NUL trimmed
+    String(WideCString),
+    /// `REG_MULTI_SZ`: a sequence of strings, split on NUL with every empty string (including
+    /// the double-NUL terminator) dropped
+    MultiString(Vec<WideCString>),
+    /// `REG_DWORD`/`REG_DWORD_BIG_ENDIAN`: a 32-bit integer, already corrected to native byte
+    /// order
+    U32(u32),
+    /// `REG_QWORD`: a 64-bit integer
+    U64(u64),
+    /// `REG_BINARY`, or any value type not decoded above: the raw bytes
+    Binary(Vec<u8>),
+}
+
+impl RegistryValue {
+    /// Decode a value's raw bytes according to its reported [`REG_VALUE_TYPE`]
+    ///
+    /// Unrecognized value types are returned as [`Self::Binary`] rather than rejected, since a
+    /// caller that only wants the raw bytes should not need to special-case every known type.
+    pub fn from_raw(ty: REG_VALUE_TYPE, data: Vec<u8>) -> WinResult<Self> {
+        match ty {
+            REG_SZ | REG_EXPAND_SZ => Ok(Self::String(WideCString::from_vec_truncate(words(&data)))),
+            REG_MULTI_SZ => Ok(Self::MultiString(
+                words(&data)
+                    .split(|&w| w == 0)
+                    .filter(|s| !s.is_empty())
+                    .map(|s| WideCString::from_vec_truncate(s.to_vec()))
+                    .collect(),
+            )),
+            REG_DWORD => Ok(Self::U32(u32::from_le_bytes(array4(&data)?))),
+            REG_DWORD_BIG_ENDIAN => Ok(Self::U32(u32::from_be_bytes(array4(&data)?))),
+            REG_QWORD => Ok(Self::U64(u64::from_le_bytes(array8(&data)?))),
+            _ => Ok(Self::Binary(data)),
+        }
+    }
+}
+
+fn words(data: &[u8]) -> Vec<u16> {
+    data.chunks_exact(2).map(|w| u16::from_le_bytes([w[0], w[1]])).collect()
+}
+
+fn array4(data: &[u8]) -> WinResult<[u8; 4]> {
+    data.get(..4)
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| win32_error(ERROR_INVALID_DATA, &format_args!("REG_DWORD value is not 4 bytes")))
+}
+
+fn array8(data: &[u8]) -> WinResult<[u8; 8]> {
+    data.get(..8)
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| win32_error(ERROR_INVALID_DATA, &format_args!("REG_QWORD value is not 8 bytes")))
+}
+
+impl Key {
+    /// Query and [decode](RegistryValue::from_raw) a named value
+    #[doc(alias = "RegQueryValueExW")]
+    pub fn query_value(&self, name: &WideCStr) -> WinResult<RegistryValue> {
+        let (ty, data) = self.win32_query_value(name)?;
+        RegistryValue::from_raw(ty, data)
+    }
+
+    /// Enumerate and [decode](RegistryValue::from_raw) every value under this key
+    #[doc(alias = "RegEnumValueW")]
+    pub fn values<'a>(&'a self) -> impl Iterator<Item = WinResult<(WideCString, RegistryValue)>> + 'a {
+        self.win32_enumerate_values().map(move |value| {
+            let (name, ..) = value?;
+            let value = self.query_value(&name)?;
+            Ok((name, value))
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use {
+    crate::registry::Key,
+    widestring::widecstr,
+    windows::{
+        core::{Error, Result as WinResult},
+        Win32::{
+            Foundation::{CloseHandle, BOOL, HANDLE, WAIT_OBJECT_0},
+            System::{
+                Registry::{
+                    RegNotifyChangeKeyValue, KEY_NOTIFY, REG_NOTIFY_CHANGE_LAST_SET,
+                    REG_NOTIFY_CHANGE_NAME, REG_OPEN_CREATE_OPTIONS,
+                },
+                Threading::{CreateEventW, ResetEvent, WaitForSingleObject, INFINITE},
+            },
+        },
+    },
+};
+
+/// Watches the display driver's configuration registry key for changes, signalling when a
+/// monitor is plugged, unplugged, or the display configuration otherwise changes
+///
+/// This wraps [`RegNotifyChangeKeyValue`][rncv] against
+/// `HKEY_LOCAL_MACHINE\SYSTEM\CurrentControlSet\Control\GraphicsDrivers\Configuration`, the key
+/// the display driver touches on every configuration change, watching for both
+/// [`REG_NOTIFY_CHANGE_NAME`] and [`REG_NOTIFY_CHANGE_LAST_SET`] so that new subkeys and
+/// modified values both trigger a signal. Callers should re-run
+/// [`DisplayDevice::enumerate`](crate::DisplayDevice::enumerate) and diff against their previous
+/// device set on each signal.
+///
+/// [rncv]: https://learn.microsoft.com/en-us/windows/win32/api/winreg/nf-winreg-regnotifychangekeyvalue
+#[doc(alias = "RegNotifyChangeKeyValue")]
+pub struct DisplayChangeWatcher {
+    key: Key,
+    event: HANDLE,
+}
+
+impl DisplayChangeWatcher {
+    /// Open the display configuration registry key and arm a new watch
+    pub fn new() -> WinResult<Self> {
+        let key = Key::HKEY_LOCAL_MACHINE.win32_open(
+            widecstr!("SYSTEM\\CurrentControlSet\\Control\\GraphicsDrivers\\Configuration"),
+            REG_OPEN_CREATE_OPTIONS(0),
+            KEY_NOTIFY,
+        )?;
+        let event = unsafe { CreateEventW(None, BOOL::from(true), BOOL::from(false), None) }?;
+        let watcher = Self { key, event };
+        watcher.watch()?;
+        Ok(watcher)
+    }
+
+    /// (Re-)arm the watch
+    ///
+    /// The underlying notification is one-shot, so this must be called again after each signal
+    /// to keep watching; [`wait`](Self::wait) does this automatically, while callers waiting on
+    /// [`event`](Self::event) directly must call it themselves.
+    #[doc(alias = "RegNotifyChangeKeyValue")]
+    pub fn watch(&self) -> WinResult<()> {
+        unsafe {
+            ResetEvent(self.event)?;
+            RegNotifyChangeKeyValue(
+                self.key.win32_handle(),
+                BOOL::from(true),
+                REG_NOTIFY_CHANGE_NAME | REG_NOTIFY_CHANGE_LAST_SET,
+                self.event,
+                BOOL::from(true),
+            )
+            .ok()
+        }
+    }
+
+    /// The event [`HANDLE`] signalled when the display configuration changes
+    ///
+    /// This is suitable for use with `WaitForMultipleObjects` or an async reactor alongside
+    /// other handles, as an alternative to the blocking [`wait`](Self::wait). Callers using the
+    /// raw handle directly are responsible for calling [`watch`](Self::watch) again after each
+    /// signal.
+    pub const fn event(&self) -> HANDLE {
+        self.event
+    }
+
+    /// Block until the display configuration changes, then re-arm the watch
+    #[doc(alias = "WaitForSingleObject")]
+    pub fn wait(&self) -> WinResult<()> {
+        match unsafe { WaitForSingleObject(self.event, INFINITE) } {
+            WAIT_OBJECT_0 => self.watch(),
+            _ => Err(Error::from_win32()),
+        }
+    }
+}
+
+impl Drop for DisplayChangeWatcher {
+    fn drop(&mut self) {
+        let _ = unsafe { CloseHandle(self.event) };
+    }
+}
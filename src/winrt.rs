@@ -0,0 +1,183 @@
+//! [`Windows.Devices.Display.DisplayMonitor`][displaymonitor] bridge
+//!
+//! GDI's [`DISPLAY_DEVICEW`](windows::Win32::Graphics::Gdi::DISPLAY_DEVICEW) exposes a monitor's
+//! name and state flags but has no notion of how the monitor is physically connected. The WinRT
+//! `DisplayMonitor` runtime class fills this gap with `ConnectionKind`, `PhysicalConnector`,
+//! `DisplayName`, and `DisplayAdapterDeviceId`, which this module surfaces as plain Rust types.
+//!
+//! A [`DisplayMonitor`] is resolved from a [`MonitorDevice`]'s
+//! [`interface_path`](crate::MonitorDevice::interface_path) by enumerating every WinRT display
+//! monitor and comparing its `DeviceId` against the requested interface path.
+//!
+//! [displaymonitor]: https://learn.microsoft.com/en-us/uwp/api/windows.devices.display.displaymonitor
+
+use {
+    crate::MonitorDevice,
+    widestring::WideStr,
+    windows::{
+        core::Result as WinResult,
+        Devices::{
+            Display::{
+                self as display, DisplayMonitorConnectionKind as WinRtConnectionKind,
+                DisplayMonitorPhysicalConnectorKind as WinRtPhysicalConnector,
+            },
+            Enumeration::DeviceInformation,
+        },
+    },
+};
+
+/// How a [`DisplayMonitor`] is physically connected, mirrored from
+/// [`DisplayMonitorConnectionKind`](windows::Devices::Display::DisplayMonitorConnectionKind)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[doc(alias = "DisplayMonitorConnectionKind")]
+#[non_exhaustive]
+pub enum ConnectionKind {
+    /// An internal panel, such as a laptop's built-in display
+    Internal,
+    /// A monitor connected over a wired video interface
+    Wired,
+    /// A monitor connected wirelessly, e.g. Miracast
+    Wireless,
+    /// A virtual or remoted display with no physical connection
+    Virtual,
+}
+
+impl From<WinRtConnectionKind> for ConnectionKind {
+    fn from(kind: WinRtConnectionKind) -> Self {
+        match kind {
+            WinRtConnectionKind::Internal => Self::Internal,
+            WinRtConnectionKind::Wireless => Self::Wireless,
+            WinRtConnectionKind::Virtual => Self::Virtual,
+            _ => Self::Wired,
+        }
+    }
+}
+
+/// The physical connector used by a [`DisplayMonitor`], mirrored from
+/// [`DisplayMonitorPhysicalConnectorKind`](windows::Devices::Display::DisplayMonitorPhysicalConnectorKind)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[doc(alias = "DisplayMonitorPhysicalConnectorKind")]
+#[non_exhaustive]
+pub enum PhysicalConnector {
+    /// The physical connector could not be determined
+    Unknown,
+    /// HD-15 (VGA)
+    Hd15,
+    /// DVI
+    Dvi,
+    /// SDI
+    Sdi,
+    /// DisplayPort, external
+    DisplayPortExternal,
+    /// DisplayPort, embedded
+    DisplayPortEmbedded,
+    /// HDMI
+    Hdmi,
+    /// LVDS
+    Lvds,
+    /// D-JPN
+    Djpn,
+    /// SDTV
+    Sdtv,
+    /// Miracast
+    Miracast,
+    /// Internal, not otherwise enumerated here
+    InternalDisplayPort,
+    /// Any connector kind not covered above
+    Other,
+}
+
+impl From<WinRtPhysicalConnector> for PhysicalConnector {
+    fn from(kind: WinRtPhysicalConnector) -> Self {
+        match kind {
+            WinRtPhysicalConnector::HD15 => Self::Hd15,
+            WinRtPhysicalConnector::Dvi => Self::Dvi,
+            WinRtPhysicalConnector::Sdi => Self::Sdi,
+            WinRtPhysicalConnector::DisplayPortExternal => Self::DisplayPortExternal,
+            WinRtPhysicalConnector::DisplayPortEmbedded => Self::DisplayPortEmbedded,
+            WinRtPhysicalConnector::Hdmi => Self::Hdmi,
+            WinRtPhysicalConnector::Lvds => Self::Lvds,
+            WinRtPhysicalConnector::DJpn => Self::Djpn,
+            WinRtPhysicalConnector::Sdtv => Self::Sdtv,
+            WinRtPhysicalConnector::Miracast => Self::Miracast,
+            WinRtPhysicalConnector::InternalDisplayPort => Self::InternalDisplayPort,
+            WinRtPhysicalConnector::Unknown => Self::Unknown,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A bridge to a WinRT [`Windows.Devices.Display.DisplayMonitor`][displaymonitor], resolved by
+/// device interface path
+///
+/// [displaymonitor]: https://learn.microsoft.com/en-us/uwp/api/windows.devices.display.displaymonitor
+#[doc(alias = "DisplayMonitor")]
+#[doc(alias = "IDisplayMonitor")]
+pub struct DisplayMonitor {
+    monitor: display::DisplayMonitor,
+}
+
+impl DisplayMonitor {
+    /// Resolve the WinRT `DisplayMonitor` whose `DeviceId` matches `interface_path`
+    ///
+    /// This enumerates every display monitor reachable through
+    /// [`DisplayMonitor::GetDeviceSelector`][selector] and compares each one's `DeviceId`
+    /// against `interface_path`, returning `None` if none match.
+    ///
+    /// [selector]: https://learn.microsoft.com/en-us/uwp/api/windows.devices.display.displaymonitor.getdeviceselector
+    pub fn from_interface_path(interface_path: &WideStr) -> WinResult<Option<Self>> {
+        let interface_path = interface_path.to_string_lossy();
+        let selector = display::DisplayMonitor::GetDeviceSelector()?;
+        let devices = DeviceInformation::FindAllAsyncAqsFilter(&selector)?.get()?;
+        for device in &devices {
+            let monitor = display::DisplayMonitor::FromInterfaceIdAsync(&device.Id()?)?.get()?;
+            if monitor.DeviceId()?.to_string_lossy() == interface_path {
+                return Ok(Some(Self { monitor }))
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolve the WinRT `DisplayMonitor` corresponding to a [`MonitorDevice`]
+    ///
+    /// Returns `None` if the monitor has no
+    /// [`interface_path`](MonitorDevice::interface_path), or if no WinRT display monitor with a
+    /// matching `DeviceId` was found.
+    pub fn from_monitor(monitor: &MonitorDevice) -> WinResult<Option<Self>> {
+        match monitor.interface_path() {
+            Some(path) => Self::from_interface_path(&path),
+            None => Ok(None),
+        }
+    }
+
+    /// How this monitor is physically connected
+    #[doc(alias = "ConnectionKind")]
+    pub fn connection_kind(&self) -> WinResult<ConnectionKind> {
+        self.monitor.ConnectionKind().map(ConnectionKind::from)
+    }
+
+    /// The physical connector used by this monitor
+    #[doc(alias = "PhysicalConnector")]
+    pub fn physical_connector(&self) -> WinResult<PhysicalConnector> {
+        self.monitor.PhysicalConnector().map(PhysicalConnector::from)
+    }
+
+    /// A friendly, human-readable name for this monitor
+    #[doc(alias = "DisplayName")]
+    pub fn display_name(&self) -> WinResult<String> {
+        self.monitor.DisplayName().map(|s| s.to_string_lossy())
+    }
+
+    /// The device ID of the adapter this monitor is connected to
+    #[doc(alias = "DisplayAdapterDeviceId")]
+    pub fn display_adapter_device_id(&self) -> WinResult<String> {
+        self.monitor.DisplayAdapterDeviceId().map(|s| s.to_string_lossy())
+    }
+
+    /// This monitor's own device ID, i.e. the device interface path it was
+    /// [resolved](Self::from_interface_path) from
+    #[doc(alias = "DeviceId")]
+    pub fn device_id(&self) -> WinResult<String> {
+        self.monitor.DeviceId().map(|s| s.to_string_lossy())
+    }
+}